@@ -0,0 +1,577 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use utils::Source;
+
+pub const USAGE: &str = "\
+Usage: aoc <command> [[year/]days] [options]
+
+Commands:
+  run       solve selected days and print answers
+  test      solve selected days and check answers against <inputs>/answers.txt
+  bench     solve selected days repeatedly and report timings
+  fetch     download missing puzzle inputs (needs $AOC_SESSION or --session)
+  gen       generate random inputs of selected days, on stdout or with --out
+            as <dir>/<day>.txt, readable again with --inputs-dir <dir>
+  fuzz      run selected days on mutated inputs, reporting the ones that make
+            a day panic or hang, minimized and saved to --out if given
+
+Days:
+  7         single day of the latest year
+  1..4      range of days of the latest year (1-4 works too)
+  2018/7    day of the given year, 2018/1..4 works too
+  2018      every day of the given year (same as 2018/all)
+  all       every registered day of every year (default)
+
+Options:
+  -p, --part <1|2>        solve only one part
+  -i, --input <path>      read input from a file instead of <inputs>/<day>.txt,
+                          `-` reads it from stdin
+      --inputs-dir <dir>  look up puzzle inputs in <dir> (default: $AOC_INPUTS,
+                          ./inputs or the inputs directory of the year's crate)
+  -n, --iterations <n>    iterations for bench (default: 10), inputs per day
+                          for fuzz (default: 1000)
+  -w, --warmup <n>        untimed iterations before bench (default: 2)
+  -j, --jobs <n>          threads run and test solve days on, a day failing or
+                          panicking doesn't stop the others (default: one per
+                          core)
+      --format <format>   output of run and bench: table (default), json or tsv
+      --json              same as --format json
+  -v, --verbose           print trace events of the days on stderr, repeat for
+                          more detail (-vv, -vvv), see also $AOC_TRACE
+      --visualize         run also draws each day's puzzle state, if it has a
+                          picture (on stderr with json and tsv)
+      --color <when>      colors in pictures: auto (default), always or never
+      --size <W>x<H>      clip pictures to W columns and H lines (default: 120x60)
+      --images <dir>      run also saves maps of days that have them as
+                          <dir>/<year>-<day>-<name>.png
+  -e, --examples          test against the puzzle description examples in
+                          <inputs>/examples instead of the puzzle inputs
+      --answers <path>    answers ledger for test (default: <inputs>/answers.txt)
+      --session <token>   adventofcode.com session cookie for fetch
+      --seed <n>          seed of gen and fuzz, the same seed gives the same
+                          inputs (default: from the clock, printed on stderr)
+      --count <n>         lines, units or steps of generated inputs, see each
+                          day (default: about the size of the real input, 20
+                          for the fuzz corpus)
+  -o, --out <dir>         gen writes inputs to files in <dir>, fuzz saves the
+                          inputs it found as <dir>/<year>-<day>-<n>.txt
+      --timeout <secs>    fuzz reports a day taking longer as a hang (default: 5)
+      --config <path>     parameters of the puzzles (default: ./aoc.toml if it
+                          exists, otherwise the ones of the puzzle descriptions)
+      --set <y.d.k=v>     override a parameter of the config, e.g.
+                          --set 2018.7.workers=2, can be repeated
+  -h, --help              print this message
+";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Run,
+    Test,
+    Bench,
+    Fetch,
+    Gen,
+    Fuzz,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorMode {
+    // colors when writing to a terminal and $NO_COLOR is not set
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Years {
+    All,
+    Latest,
+    Year(u16),
+}
+
+impl Years {
+    pub fn select(&self, available: &[u16]) -> Vec<u16> {
+        match self {
+            Years::All => available.to_vec(),
+            Years::Latest => available.iter().max().cloned().into_iter().collect(),
+            Years::Year(year) => available
+                .iter()
+                .cloned()
+                .filter(|available| available == year)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Days {
+    All,
+    Range(u8, u8),
+}
+
+impl Days {
+    pub fn select(&self, available: &[u8]) -> Vec<u8> {
+        available
+            .iter()
+            .cloned()
+            .filter(|day| match self {
+                Days::All => true,
+                Days::Range(from, to) => from <= day && day <= to,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub years: Years,
+    pub days: Days,
+    pub parts: Vec<Part>,
+    pub input: Option<Source>,
+    pub inputs_dir: Option<PathBuf>,
+    pub iterations: usize,
+    pub warmup: usize,
+    // None uses every core
+    pub jobs: Option<usize>,
+    pub format: Format,
+    pub verbosity: usize,
+    pub visualize: bool,
+    pub color: ColorMode,
+    pub size: (usize, usize),
+    pub images: Option<PathBuf>,
+    pub session: Option<String>,
+    pub answers: Option<PathBuf>,
+    pub examples: bool,
+    pub seed: Option<u64>,
+    pub count: Option<usize>,
+    pub out: Option<PathBuf>,
+    pub timeout: Duration,
+    pub config: Option<PathBuf>,
+    // `year.day.key=value` of every --set, applied over the config in order
+    pub overrides: Vec<String>,
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(day) if day > 0 => Ok(day),
+        _ => Err(format!("invalid day: {:?}", value)),
+    }
+}
+
+// First year of Advent of Code, anything below is a day number
+const FIRST_YEAR: u16 = 2015;
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.trim().parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(format!("invalid year: {:?}", value)),
+    }
+}
+
+// `2018/1..4`, `2018`, `all` or days of the latest year
+fn parse_selection(value: &str) -> Result<(Years, Days), String> {
+    if value == "all" {
+        return Ok((Years::All, Days::All));
+    }
+
+    if let Some((year, days)) = value.split_once('/') {
+        return Ok((Years::Year(parse_year(year)?), parse_days(days)?));
+    }
+
+    match value.parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok((Years::Year(year), Days::All)),
+        _ => Ok((Years::Latest, parse_days(value)?)),
+    }
+}
+
+fn parse_days(value: &str) -> Result<Days, String> {
+    if value == "all" {
+        return Ok(Days::All);
+    }
+
+    let bounds = value.split_once("..").or_else(|| value.split_once('-'));
+
+    match bounds {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("empty range of days: {}", value));
+            }
+            Ok(Days::Range(from, to))
+        }
+        None => {
+            let day = parse_day(value)?;
+            Ok(Days::Range(day, day))
+        }
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part: {:?}, expected 1 or 2", value)),
+    }
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "table" => Ok(Format::Table),
+        "json" => Ok(Format::Json),
+        "tsv" => Ok(Format::Tsv),
+        _ => Err(format!(
+            "invalid format: {:?}, expected table, json or tsv",
+            value
+        )),
+    }
+}
+
+fn parse_color(value: &str) -> Result<ColorMode, String> {
+    match value {
+        "auto" => Ok(ColorMode::Auto),
+        "always" => Ok(ColorMode::Always),
+        "never" => Ok(ColorMode::Never),
+        _ => Err(format!(
+            "invalid color mode: {:?}, expected auto, always or never",
+            value
+        )),
+    }
+}
+
+// `<width>x<height>`, both above zero
+fn parse_size(value: &str) -> Result<(usize, usize), String> {
+    let size = value
+        .split_once('x')
+        .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)));
+
+    match size {
+        Some((width, height)) if width > 0 && height > 0 => Ok((width, height)),
+        _ => Err(format!("invalid size: {:?}, expected <W>x<H>", value)),
+    }
+}
+
+fn expect_value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("missing value for {}", flag))
+}
+
+// Returns Ok(None) when help was requested
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("test") => Command::Test,
+        Some("bench") => Command::Bench,
+        Some("fetch") => Command::Fetch,
+        Some("gen") => Command::Gen,
+        Some("fuzz") => Command::Fuzz,
+        Some("-h") | Some("--help") | Some("help") | None => return Ok(None),
+        Some(other) => return Err(format!("unknown command: {}", other)),
+    };
+
+    let mut options = Options {
+        command,
+        years: Years::All,
+        days: Days::All,
+        parts: vec![Part::One, Part::Two],
+        input: None,
+        inputs_dir: None,
+        iterations: if command == Command::Fuzz { 1000 } else { 10 },
+        warmup: 2,
+        jobs: None,
+        format: Format::Table,
+        verbosity: 0,
+        visualize: false,
+        color: ColorMode::Auto,
+        size: (120, 60),
+        images: None,
+        session: None,
+        answers: None,
+        examples: false,
+        seed: None,
+        count: None,
+        out: None,
+        timeout: Duration::from_secs(5),
+        config: None,
+        overrides: vec![],
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-p" | "--part" => {
+                options.parts = vec![parse_part(&expect_value(&arg, args.next())?)?];
+            }
+            "-i" | "--input" => {
+                options.input = match expect_value(&arg, args.next())?.as_str() {
+                    "-" => Some(Source::Stdin),
+                    path => Some(Source::Path(PathBuf::from(path))),
+                };
+            }
+            "--inputs-dir" => {
+                options.inputs_dir = Some(PathBuf::from(expect_value(&arg, args.next())?));
+            }
+            "-n" | "--iterations" => {
+                let value = expect_value(&arg, args.next())?;
+                options.iterations = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("invalid number of iterations: {:?}", value)),
+                };
+            }
+            "-j" | "--jobs" => {
+                let value = expect_value(&arg, args.next())?;
+                options.jobs = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid number of jobs: {:?}", value)),
+                };
+            }
+            "-e" | "--examples" => options.examples = true,
+            "--answers" => {
+                options.answers = Some(PathBuf::from(expect_value(&arg, args.next())?));
+            }
+            "-w" | "--warmup" => {
+                let value = expect_value(&arg, args.next())?;
+                options.warmup = value
+                    .parse()
+                    .map_err(|_| format!("invalid number of warmup iterations: {:?}", value))?;
+            }
+            "--format" => options.format = parse_format(&expect_value(&arg, args.next())?)?,
+            "--json" => options.format = Format::Json,
+            "--visualize" => options.visualize = true,
+            "--verbose" => options.verbosity += 1,
+            flag if flag.len() > 1
+                && flag.trim_start_matches('-') == "v".repeat(flag.len() - 1) =>
+            {
+                options.verbosity += flag.len() - 1;
+            }
+            "--color" => options.color = parse_color(&expect_value(&arg, args.next())?)?,
+            "--size" => options.size = parse_size(&expect_value(&arg, args.next())?)?,
+            "--images" => {
+                options.images = Some(PathBuf::from(expect_value(&arg, args.next())?));
+            }
+            "--session" => options.session = Some(expect_value(&arg, args.next())?),
+            "--seed" => {
+                let value = expect_value(&arg, args.next())?;
+                options.seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {:?}", value))?,
+                );
+            }
+            "--count" => {
+                let value = expect_value(&arg, args.next())?;
+                options.count = match value.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("invalid count: {:?}", value)),
+                };
+            }
+            "-o" | "--out" => options.out = Some(PathBuf::from(expect_value(&arg, args.next())?)),
+            "--timeout" => {
+                let value = expect_value(&arg, args.next())?;
+                options.timeout = match value.parse() {
+                    Ok(secs) if secs > 0 => Duration::from_secs(secs),
+                    _ => return Err(format!("invalid timeout: {:?}", value)),
+                };
+            }
+            "--config" => {
+                options.config = Some(PathBuf::from(expect_value(&arg, args.next())?));
+            }
+            "--set" => options.overrides.push(expect_value(&arg, args.next())?),
+            flag if flag.starts_with('-') => return Err(format!("unknown option: {}", flag)),
+            selection => (options.years, options.days) = parse_selection(selection)?,
+        }
+    }
+
+    if options.input.is_some() {
+        match (options.years, &options.days) {
+            (Years::All, _) => return Err(String::from("--input requires a single day")),
+            (_, Days::Range(from, to)) if from == to => {}
+            _ => return Err(String::from("--input requires a single day")),
+        }
+    }
+
+    // several inputs on stdout couldn't be told apart
+    if options.command == Command::Gen && options.out.is_none() {
+        match (options.years, &options.days) {
+            (Years::All, _) => return Err(String::from("gen requires a single day or --out")),
+            (_, Days::Range(from, to)) if from == to => {}
+            _ => return Err(String::from("gen requires a single day or --out")),
+        }
+    }
+
+    Ok(Some(options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Option<Options>, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("all"), Ok(Days::All));
+        assert_eq!(parse_days("7"), Ok(Days::Range(7, 7)));
+        assert_eq!(parse_days("1..4"), Ok(Days::Range(1, 4)));
+        assert_eq!(parse_days("2-3"), Ok(Days::Range(2, 3)));
+        assert!(parse_days("4..1").is_err());
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("all"), Ok((Years::All, Days::All)));
+        assert_eq!(parse_selection("7"), Ok((Years::Latest, Days::Range(7, 7))));
+        assert_eq!(
+            parse_selection("2018/1..4"),
+            Ok((Years::Year(2018), Days::Range(1, 4)))
+        );
+        assert_eq!(
+            parse_selection("2018/all"),
+            Ok((Years::Year(2018), Days::All))
+        );
+        assert_eq!(parse_selection("2018"), Ok((Years::Year(2018), Days::All)));
+        assert!(parse_selection("18/7").is_err());
+        assert!(parse_selection("2018/").is_err());
+    }
+
+    #[test]
+    fn test_select_years() {
+        let available = vec![2018, 2019];
+
+        assert_eq!(Years::All.select(&available), available);
+        assert_eq!(Years::Latest.select(&available), vec![2019]);
+        assert_eq!(Years::Year(2018).select(&available), vec![2018]);
+        assert_eq!(Years::Year(2017).select(&available), vec![]);
+        assert_eq!(Years::Latest.select(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_select_days() {
+        let available = vec![1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!(Days::All.select(&available), available);
+        assert_eq!(Days::Range(3, 5).select(&available), vec![3, 4, 5]);
+        assert_eq!(Days::Range(6, 25).select(&available), vec![6, 7]);
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse("run 5 --part 2 -i polymer.txt").unwrap().unwrap();
+
+        assert_eq!(options.command, Command::Run);
+        assert_eq!(options.years, Years::Latest);
+        assert_eq!(options.days, Days::Range(5, 5));
+        assert_eq!(options.parts, vec![Part::Two]);
+        assert_eq!(
+            options.input,
+            Some(Source::Path(PathBuf::from("polymer.txt")))
+        );
+
+        let options = parse("run 2018/7 --input -").unwrap().unwrap();
+        assert_eq!(options.years, Years::Year(2018));
+        assert_eq!(options.input, Some(Source::Stdin));
+
+        let options = parse("test 1..3 --inputs-dir /tmp/aoc").unwrap().unwrap();
+        assert_eq!(options.command, Command::Test);
+        assert_eq!(options.inputs_dir, Some(PathBuf::from("/tmp/aoc")));
+
+        let options = parse("test 5 -e").unwrap().unwrap();
+        assert!(options.examples);
+
+        let options = parse("bench -n 3").unwrap().unwrap();
+        assert_eq!(options.command, Command::Bench);
+        assert_eq!(options.days, Days::All);
+        assert_eq!(options.iterations, 3);
+        assert_eq!(options.warmup, 2);
+        assert_eq!(options.format, Format::Table);
+
+        assert_eq!(parse("run all -j 3").unwrap().unwrap().jobs, Some(3));
+        assert_eq!(parse("run all").unwrap().unwrap().jobs, None);
+        assert!(parse("run all --jobs 0").is_err());
+
+        let options = parse("bench 6 --warmup 0 --json").unwrap().unwrap();
+        assert_eq!(options.warmup, 0);
+        assert_eq!(options.format, Format::Json);
+
+        let options = parse("run 4 --format tsv").unwrap().unwrap();
+        assert_eq!(options.format, Format::Tsv);
+        assert!(parse("run --format xml").is_err());
+
+        let options = parse("run 3 --visualize --color never --size 80x24")
+            .unwrap()
+            .unwrap();
+        assert!(options.visualize);
+        assert_eq!(options.verbosity, 0);
+        assert_eq!(options.color, ColorMode::Never);
+        assert_eq!(options.size, (80, 24));
+        assert!(parse("run --size 80").is_err());
+        assert!(parse("run --size 0x24").is_err());
+        assert!(parse("run --color sometimes").is_err());
+
+        let options = parse("run 6 --images maps").unwrap().unwrap();
+        assert_eq!(options.images, Some(PathBuf::from("maps")));
+
+        assert_eq!(parse("run -v").unwrap().unwrap().verbosity, 1);
+        assert_eq!(parse("run -vvv -v").unwrap().unwrap().verbosity, 4);
+        assert_eq!(parse("run --verbose -vv").unwrap().unwrap().verbosity, 3);
+        assert!(parse("run -vx").is_err());
+
+        let options = parse("gen 2018/4 --seed 42 --count 100").unwrap().unwrap();
+        assert_eq!(options.command, Command::Gen);
+        assert_eq!((options.seed, options.count), (Some(42), Some(100)));
+
+        let options = parse("gen 2018 -o /tmp/big").unwrap().unwrap();
+        assert_eq!(options.out, Some(PathBuf::from("/tmp/big")));
+        assert!(parse("gen 2018").is_err());
+        assert!(parse("gen 5 --seed -1").is_err());
+        assert!(parse("gen 5 --count 0").is_err());
+
+        let options = parse("fuzz 2018 --seed 7 --timeout 2").unwrap().unwrap();
+        assert_eq!(options.command, Command::Fuzz);
+        assert_eq!(options.iterations, 1000);
+        assert_eq!(options.timeout, Duration::from_secs(2));
+        assert_eq!(parse("fuzz 3 -n 50").unwrap().unwrap().iterations, 50);
+        assert!(parse("fuzz 3 --timeout 0").is_err());
+
+        let options =
+            parse("run 7 --config small.toml --set 2018.7.workers=2 --set 2018.7.base_seconds=0")
+                .unwrap()
+                .unwrap();
+        assert_eq!(options.config, Some(PathBuf::from("small.toml")));
+        assert_eq!(
+            options.overrides,
+            vec!["2018.7.workers=2", "2018.7.base_seconds=0"]
+        );
+        assert!(parse("run 7 --set").is_err());
+
+        assert_eq!(parse("run --help"), Ok(None));
+        assert!(parse("run all --input x.txt").is_err());
+        assert!(parse("run 2018 --input x.txt").is_err());
+        assert!(parse("run --part 3").is_err());
+        assert!(parse("walk 1").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::runner;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::panic;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+use utils::fuzz::{self, Mutator};
+use utils::{Config, Puzzle, Rng};
+
+thread_local! {
+    // where the last panic of the thread was raised, set by the panic hook
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Keeps panics off stderr and remembers where they happened, so findings can
+// be told apart and a minimized input checked to fail the same way
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|info| {
+        let location = info
+            .location()
+            .map(|location| format!("{}:{}", location.file(), location.line()));
+        LOCATION.with(|last| *last.borrow_mut() = location);
+    }));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+    Panic { message: String, location: String },
+    // no answer within the timeout
+    Hang,
+}
+
+pub struct Finding {
+    pub failure: Failure,
+    pub input: String,
+}
+
+pub struct FuzzResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub inputs: usize,
+    // inputs that parsed and went on to the parts
+    pub parsed: usize,
+    pub findings: Vec<Finding>,
+}
+
+//
+// Thread running a single day on inputs sent to it, so a day that hangs can
+// be given up on. The thread builds its own registries from the config of
+// the run, days aren't `Send`.
+//
+struct Worker {
+    inputs: Sender<String>,
+    outcomes: Receiver<Result<bool, Failure>>,
+}
+
+impl Worker {
+    fn spawn(year: u16, day: u8, config: Config) -> Worker {
+        let (inputs, received) = mpsc::channel::<String>();
+        let (sent, outcomes) = mpsc::channel();
+
+        thread::spawn(move || {
+            let registries = crate::registries(&config).expect("config of the run is valid");
+            let puzzle = registries
+                .iter()
+                .find(|registry| registry.year() == year)
+                .and_then(|registry| registry.get(day))
+                .expect("fuzzed day is registered");
+
+            for input in received {
+                let outcome =
+                    runner::catch(|| fuzz::run_target(puzzle, &input)).map_err(|message| {
+                        Failure::Panic {
+                            message,
+                            location: LOCATION
+                                .with(|last| last.borrow_mut().take())
+                                .unwrap_or_default(),
+                        }
+                    });
+                if sent.send(outcome).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Worker { inputs, outcomes }
+    }
+
+    fn run(&self, input: &str, timeout: Duration) -> Result<bool, Failure> {
+        if self.inputs.send(input.to_string()).is_err() {
+            return Err(Failure::Hang);
+        }
+
+        self.outcomes
+            .recv_timeout(timeout)
+            .unwrap_or(Err(Failure::Hang))
+    }
+}
+
+struct Fuzzer {
+    year: u16,
+    day: u8,
+    timeout: Duration,
+    config: Config,
+    worker: Worker,
+}
+
+impl Fuzzer {
+    fn new(year: u16, day: u8, config: &Config, timeout: Duration) -> Fuzzer {
+        Fuzzer {
+            year,
+            day,
+            timeout,
+            config: config.clone(),
+            worker: Worker::spawn(year, day, config.clone()),
+        }
+    }
+
+    // A hanging worker is left behind and replaced by a fresh one
+    fn run(&mut self, input: &str) -> Result<bool, Failure> {
+        let outcome = self.worker.run(input, self.timeout);
+
+        if outcome == Err(Failure::Hang) {
+            self.worker = Worker::spawn(self.year, self.day, self.config.clone());
+        }
+        outcome
+    }
+}
+
+//
+// Runs `iterations` mutations of `corpus` through every entry point of the
+// day. Each place a panic comes from is reported once, with the smallest
+// input found that panics there. The first hang ends the day's run, every
+// further hang would leave another thread spinning.
+//
+pub fn fuzz_day(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    config: &Config,
+    corpus: Vec<String>,
+    iterations: usize,
+    timeout: Duration,
+    rng: &mut Rng,
+) -> FuzzResult {
+    let mutator = Mutator::new(corpus);
+    let mut fuzzer = Fuzzer::new(year, puzzle.day(), config, timeout);
+    let mut locations = HashSet::new();
+    let mut result = FuzzResult {
+        year,
+        day: puzzle.day(),
+        title: puzzle.title(),
+        inputs: 0,
+        parsed: 0,
+        findings: vec![],
+    };
+
+    for _ in 0..iterations {
+        let input = mutator.next(rng);
+        result.inputs += 1;
+
+        let location = match fuzzer.run(&input) {
+            Ok(parsed) => {
+                result.parsed += usize::from(parsed);
+                continue;
+            }
+            Err(Failure::Panic { location, .. }) => location,
+            Err(Failure::Hang) => {
+                result.findings.push(Finding {
+                    failure: Failure::Hang,
+                    input,
+                });
+                break;
+            }
+        };
+        if !locations.insert(location.clone()) {
+            continue;
+        }
+
+        let input = fuzz::minimize(&input, |candidate| {
+            matches!(fuzzer.run(candidate),
+                Err(Failure::Panic { location: other, .. }) if other == location)
+        });
+        // message of the minimized input, it may name other values
+        if let Err(failure) = fuzzer.run(&input) {
+            result.findings.push(Finding { failure, input });
+        }
+    }
+
+    result
+}
@@ -0,0 +1,569 @@
+extern crate aoc2018;
+extern crate utils;
+
+mod cli;
+mod fuzzer;
+mod pool;
+mod report;
+mod runner;
+
+use cli::{ColorMode, Command, Format, Options};
+use pool::Job;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+use utils::config::CONFIG_FILE;
+use utils::examples::{self, Examples};
+use utils::fetch::{self, CurlTransport, Fetcher};
+use utils::input;
+use utils::ledger::Ledger;
+use utils::trace::{self, Level};
+use utils::{Canvas, Config, ConfigError, Error, InputError, Puzzle, Registry, Rng, Source, Style};
+
+// Every year the runner knows about, with the parameters of `config`
+fn registries(config: &Config) -> Result<Vec<Registry>, ConfigError> {
+    let registries = vec![aoc2018::registry_with(config)?];
+
+    // a section nobody reads is a typo in the year or day
+    for (year, day) in config.days() {
+        let registered = registries
+            .iter()
+            .any(|registry| registry.year() == year && registry.get(day).is_some());
+        if !registered {
+            return Err(ConfigError::UnknownDay { year, day });
+        }
+    }
+
+    Ok(registries)
+}
+
+fn registries_or_exit(config: &Config) -> Vec<Registry> {
+    registries(config).unwrap_or_else(|err| {
+        eprintln!("aoc: config: {}", err);
+        process::exit(2);
+    })
+}
+
+fn apply_overrides(config: &mut Config, options: &Options) {
+    for assignment in &options.overrides {
+        if let Err(err) = config.set(assignment) {
+            eprintln!("aoc: --set: {}", err);
+            process::exit(2);
+        }
+    }
+}
+
+// `--config`, `./aoc.toml` or nothing, then every `--set`
+fn load_config(options: &Options) -> Config {
+    let path = options
+        .config
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(CONFIG_FILE));
+    let mut config = Config::load_or_default(options.config.as_deref()).unwrap_or_else(|err| {
+        match err {
+            ConfigError::Unreadable(..) => eprintln!("aoc: {}", err),
+            err => eprintln!("aoc: {}: {}", path.display(), err),
+        }
+        process::exit(2);
+    });
+
+    apply_overrides(&mut config, options);
+    config
+}
+
+fn read_input(registry: &Registry, day: u8, options: &Options) -> Result<String, InputError> {
+    let source = options.input.clone().unwrap_or(Source::Day(day));
+
+    source.read_from(&registry.inputs_dirs(options.inputs_dir.as_deref()))
+}
+
+fn read_input_or_exit(registry: &Registry, day: u8, options: &Options) -> String {
+    read_input(registry, day, options).unwrap_or_else(|err| {
+        eprintln!("aoc: {}/{}: {}", registry.year(), day, err);
+        process::exit(1);
+    })
+}
+
+// Diagnostic of a day that can't be solved, instead of a backtrace
+fn exit_error(registry: &Registry, day: u8, err: Error) -> ! {
+    let label = format!("{}/{}", registry.year(), day);
+
+    match err {
+        Error::Parse(errors) => eprintln!("aoc: {}: invalid input:\n{}", label, errors),
+        err => eprintln!("aoc: {}: {}", label, err),
+    }
+    process::exit(1);
+}
+
+fn load_ledger(registry: &Registry, options: &Options) -> Ledger {
+    let path = match &options.answers {
+        Some(path) => Some(path.clone()),
+        None => input::find_in_inputs(
+            "answers.txt",
+            &registry.inputs_dirs(options.inputs_dir.as_deref()),
+        ),
+    };
+
+    match path {
+        Some(path) => Ledger::load(&path).unwrap_or_else(|err| {
+            eprintln!("aoc: answers ledger: {}", err);
+            process::exit(1);
+        }),
+        None => {
+            eprintln!(
+                "aoc: no answers.txt found for {}, every answer is reported as missing",
+                registry.year()
+            );
+            Ledger::new()
+        }
+    }
+}
+
+fn canvas(options: &Options) -> Canvas {
+    let style = match options.color {
+        ColorMode::Always => Style::Ansi,
+        ColorMode::Never => Style::Plain,
+        ColorMode::Auto if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() => {
+            Style::Ansi
+        }
+        ColorMode::Auto => Style::Plain,
+    };
+
+    Canvas::new(style).with_limits(options.size.0, options.size.1)
+}
+
+// Pictures go after the answers, on stderr when stdout is for machines
+fn print_visualizations(puzzles: &[(&Registry, &dyn Puzzle, String)], options: &Options) {
+    for (registry, puzzle, input) in puzzles {
+        let mut canvas = canvas(options);
+        runner::visualize(*puzzle, input, &mut canvas);
+
+        let picture = if canvas.is_empty() {
+            String::from("(no visualization)\n")
+        } else {
+            canvas.to_string()
+        };
+        let picture = format!(
+            "\n{}/{} {}\n{}",
+            registry.year(),
+            puzzle.day(),
+            puzzle.title(),
+            picture
+        );
+
+        match options.format {
+            Format::Table => print!("{}", picture),
+            Format::Json | Format::Tsv => eprint!("{}", picture),
+        }
+    }
+}
+
+fn save_images(puzzles: &[(&Registry, &dyn Puzzle, String)], dir: &Path) {
+    if let Err(err) = fs::create_dir_all(dir) {
+        eprintln!("aoc: {}: {}", dir.display(), err);
+        process::exit(1);
+    }
+
+    for (registry, puzzle, input) in puzzles {
+        for (name, image) in runner::images(*puzzle, input) {
+            let file = format!("{}-{}-{}.png", registry.year(), puzzle.day(), name);
+            let path = dir.join(file);
+
+            match image.save(&path) {
+                Ok(()) => eprintln!("aoc: saved {}", path.display()),
+                Err(err) => {
+                    eprintln!("aoc: {}: {}", path.display(), err);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+fn test_examples(selected: &[(&Registry, Vec<u8>)], options: &Options) {
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut results = vec![];
+
+    for (registry, days) in selected {
+        let examples =
+            examples::find_examples_dir(&registry.inputs_dirs(options.inputs_dir.as_deref()))
+                .and_then(|dir| Examples::load(&dir))
+                .unwrap_or_else(|err| {
+                    eprintln!("aoc: {}: {}", registry.year(), err);
+                    process::exit(1);
+                });
+
+        // the examples' parameters instead of the puzzles', `--set` still applies
+        let mut config = examples.config.clone();
+        apply_overrides(&mut config, options);
+        let configured = registries_or_exit(&config);
+        let registry = configured
+            .iter()
+            .find(|configured| configured.year() == registry.year())
+            .unwrap_or(registry);
+
+        for puzzle in days.iter().filter_map(|day| registry.get(*day)) {
+            for example in examples.for_day(puzzle.day()) {
+                let checked = runner::catch(|| examples.check(puzzle, example));
+                results.push((registry.year(), example.id.clone(), puzzle.title(), checked));
+            }
+        }
+    }
+
+    let summary = report::print_example_results(&results);
+
+    if summary.failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn fetch_inputs(selected: &[(&Registry, Vec<u8>)], options: &Options) {
+    let session = match &options.session {
+        Some(session) => Ok(session.clone()),
+        None => fetch::session_from_env(),
+    };
+    let session = session.unwrap_or_else(|err| {
+        eprintln!("aoc: {}", err);
+        process::exit(1);
+    });
+
+    let mut failed = false;
+
+    for (registry, days) in selected {
+        // explicit inputs dir, $AOC_INPUTS or the inputs directory of the year's crate
+        let cache_dir = registry
+            .inputs_dirs(options.inputs_dir.as_deref())
+            .pop()
+            .unwrap_or_default();
+        let mut fetcher = Fetcher::new(CurlTransport, &session, registry.year(), &cache_dir);
+
+        for day in days {
+            let label = format!("{}/{}", registry.year(), day);
+
+            match fetcher.fetch(*day) {
+                Ok((path, true)) => println!("{}: downloaded {}", label, path.display()),
+                Ok((path, false)) => println!("{}: cached {}", label, path.display()),
+                Err(err) => {
+                    eprintln!("{}: {}", label, err);
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+//
+// Random inputs of the selected days, every day generated from the same seed.
+// A single day goes to stdout, with `--out` each day is written to
+// `<dir>/<day>.txt` where `--inputs-dir <dir>` finds it.
+//
+fn generate_inputs(selected: &[(&Registry, Vec<u8>)], options: &Options) {
+    let seed = options.seed.unwrap_or_else(Rng::time_seed);
+    eprintln!("aoc: seed {}", seed);
+
+    if selected.len() > 1 && options.out.is_some() {
+        eprintln!("aoc: gen --out writes days of a single year, select one");
+        process::exit(2);
+    }
+    if let Some(dir) = &options.out {
+        if let Err(err) = fs::create_dir_all(dir) {
+            eprintln!("aoc: {}: {}", dir.display(), err);
+            process::exit(1);
+        }
+    }
+
+    for (registry, days) in selected {
+        for puzzle in days.iter().filter_map(|day| registry.get(*day)) {
+            let label = format!("{}/{}", registry.year(), puzzle.day());
+            let input = match puzzle.generate(&mut Rng::new(seed), options.count) {
+                Some(input) => input,
+                None => {
+                    eprintln!("aoc: {}: no generator", label);
+                    continue;
+                }
+            };
+
+            let dir = match &options.out {
+                Some(dir) => dir,
+                None => {
+                    print!("{}", input);
+                    continue;
+                }
+            };
+            let path = dir.join(format!("{}.txt", puzzle.day()));
+
+            match fs::write(&path, input) {
+                Ok(()) => eprintln!("aoc: {}: wrote {}", label, path.display()),
+                Err(err) => {
+                    eprintln!("aoc: {}: {}", path.display(), err);
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+// Inputs the fuzzer starts from: the day's examples and generated inputs
+fn fuzz_corpus(
+    registry: &Registry,
+    puzzle: &dyn Puzzle,
+    options: &Options,
+    seed: u64,
+) -> Vec<String> {
+    let mut corpus: Vec<String> =
+        examples::find_examples_dir(&registry.inputs_dirs(options.inputs_dir.as_deref()))
+            .and_then(|dir| Examples::load(&dir))
+            .map(|examples| {
+                examples
+                    .for_day(puzzle.day())
+                    .map(|example| example.input.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+    for idx in 0..5 {
+        let mut rng = Rng::new(seed.wrapping_add(idx));
+        corpus.extend(puzzle.generate(&mut rng, Some(options.count.unwrap_or(20))));
+    }
+
+    corpus
+}
+
+//
+// Mutated inputs through every selected day. Findings are minimized, printed
+// and, with `--out`, saved as `<dir>/<year>-<day>-<n>.txt`.
+//
+fn fuzz_days(selected: &[(&Registry, Vec<u8>)], config: &Config, options: &Options) {
+    let seed = options.seed.unwrap_or_else(Rng::time_seed);
+    eprintln!("aoc: seed {}", seed);
+
+    if let Some(dir) = &options.out {
+        if let Err(err) = fs::create_dir_all(dir) {
+            eprintln!("aoc: {}: {}", dir.display(), err);
+            process::exit(1);
+        }
+    }
+    fuzzer::install_panic_hook();
+
+    let mut rng = Rng::new(seed);
+    let mut results = vec![];
+
+    for (registry, days) in selected {
+        for puzzle in days.iter().filter_map(|day| registry.get(*day)) {
+            let corpus = fuzz_corpus(registry, puzzle, options, seed);
+            if corpus.is_empty() {
+                eprintln!(
+                    "aoc: {}/{}: no examples or generator",
+                    registry.year(),
+                    puzzle.day()
+                );
+                continue;
+            }
+
+            results.push(fuzzer::fuzz_day(
+                registry.year(),
+                puzzle,
+                config,
+                corpus,
+                options.iterations,
+                options.timeout,
+                &mut rng,
+            ));
+        }
+    }
+
+    let found = report::print_fuzz_results(&results);
+
+    if let Some(dir) = &options.out {
+        for result in &results {
+            for (idx, finding) in result.findings.iter().enumerate() {
+                let file = format!("{}-{}-{}.txt", result.year, result.day, idx + 1);
+                let path = dir.join(file);
+
+                match fs::write(&path, &finding.input) {
+                    Ok(()) => eprintln!("aoc: saved {}", path.display()),
+                    Err(err) => {
+                        eprintln!("aoc: {}: {}", path.display(), err);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+
+    if found > 0 {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let options = match cli::parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", cli::USAGE);
+            return;
+        }
+        Err(message) => {
+            eprintln!("aoc: {}\n", message);
+            eprint!("{}", cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    trace::init_from_env();
+    if options.verbosity > 0 {
+        trace::set_level(Level::from_verbosity(options.verbosity));
+    }
+
+    let config = load_config(&options);
+    let registries = registries_or_exit(&config);
+    let available: Vec<u16> = registries.iter().map(|registry| registry.year()).collect();
+    let years = options.years.select(&available);
+
+    // selected days grouped by year, years without a selected day are left out
+    let selected: Vec<(&Registry, Vec<u8>)> = registries
+        .iter()
+        .filter(|registry| years.contains(&registry.year()))
+        .map(|registry| (registry, options.days.select(&registry.days())))
+        .filter(|(_, days)| !days.is_empty())
+        .collect();
+
+    if selected.is_empty() {
+        eprintln!(
+            "aoc: no registered days match {:?} of {:?}",
+            options.days, options.years
+        );
+        process::exit(2);
+    }
+
+    // every selected puzzle with the registry of its year
+    let puzzles = || {
+        selected.iter().flat_map(|(registry, days)| {
+            days.iter()
+                .filter_map(|day| registry.get(*day))
+                .map(move |puzzle| (*registry, puzzle))
+        })
+    };
+
+    // every selected day with its input, solved on the thread pool
+    let threads = options.jobs.unwrap_or_else(pool::default_threads);
+    let solve_all = || {
+        let jobs: Vec<Job> = puzzles()
+            .map(|(registry, puzzle)| Job {
+                year: registry.year(),
+                day: puzzle.day(),
+                input: read_input(registry, puzzle.day(), &options).map_err(|err| err.to_string()),
+            })
+            .collect();
+
+        let started = Instant::now();
+        let results = pool::solve_days(&jobs, &config, &options.parts, threads);
+        let wall = started.elapsed();
+
+        let runs: Vec<report::DayRun> = puzzles()
+            .zip(results)
+            .map(|((registry, puzzle), result)| {
+                (registry.year(), puzzle.day(), puzzle.title(), result)
+            })
+            .collect();
+        (jobs, runs, wall)
+    };
+
+    match options.command {
+        Command::Run => {
+            // failures are reported with their day, not as backtraces
+            panic::set_hook(Box::new(|_| {}));
+
+            let (jobs, runs, wall) = solve_all();
+            let failed = match options.format {
+                Format::Table => report::print_results(&runs, wall, threads.min(jobs.len())),
+                Format::Json | Format::Tsv => {
+                    let mut results = vec![];
+                    for (year, day, _, result) in runs {
+                        match result {
+                            Ok(result) => results.push(result),
+                            Err(message) => eprintln!("aoc: {}/{}: {}", year, day, message),
+                        }
+                    }
+
+                    match options.format {
+                        Format::Json => println!("{}", report::results_json(&results).pretty()),
+                        _ => print!("{}", report::results_tsv(&results)),
+                    }
+                    let unanswered = results.iter().filter(|result| result.failed()).count();
+                    jobs.len() - results.len() + unanswered
+                }
+            };
+
+            // pictures of the days that have an input
+            let inputs: Vec<_> = puzzles()
+                .zip(jobs)
+                .filter_map(|((registry, puzzle), job)| {
+                    job.input.ok().map(|input| (registry, puzzle, input))
+                })
+                .collect();
+            if options.visualize {
+                print_visualizations(&inputs, &options);
+            }
+            if let Some(dir) = &options.images {
+                save_images(&inputs, dir);
+            }
+
+            if failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Test if options.examples => test_examples(&selected, &options),
+        Command::Test => {
+            let ledgers: BTreeMap<u16, Ledger> = selected
+                .iter()
+                .map(|(registry, _)| (registry.year(), load_ledger(registry, &options)))
+                .collect();
+
+            // failures are reported in the table, not as backtraces
+            panic::set_hook(Box::new(|_| {}));
+
+            let (_, runs, _) = solve_all();
+            let summary = report::print_test_results(&runs, &ledgers);
+
+            if summary.failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Bench => {
+            let results: Vec<_> = puzzles()
+                .map(|(registry, puzzle)| {
+                    let input = read_input_or_exit(registry, puzzle.day(), &options);
+                    runner::bench(
+                        registry.year(),
+                        puzzle,
+                        &input,
+                        &options.parts,
+                        options.iterations,
+                        options.warmup,
+                    )
+                    .unwrap_or_else(|err| exit_error(registry, puzzle.day(), err))
+                })
+                .collect();
+
+            match options.format {
+                Format::Table => report::print_bench_results(&results),
+                Format::Json => println!("{}", report::bench_results_json(&results).pretty()),
+                Format::Tsv => print!("{}", report::bench_results_tsv(&results)),
+            }
+        }
+        Command::Fetch => fetch_inputs(&selected, &options),
+        Command::Gen => generate_inputs(&selected, &options),
+        Command::Fuzz => fuzz_days(&selected, &config, &options),
+    }
+}
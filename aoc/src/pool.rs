@@ -0,0 +1,182 @@
+use crate::cli::Part;
+use crate::runner::{self, DayResult};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use utils::{Config, Registry};
+
+// Day to solve and its input, or why the input couldn't be read
+pub struct Job {
+    pub year: u16,
+    pub day: u8,
+    pub input: Result<String, String>,
+}
+
+// Threads to use when `--jobs` isn't given, one per core
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+//
+// Solves every job on up to `threads` threads, results come back in the order
+// of the jobs. Each thread builds its own registries from `config` (days
+// aren't `Send`) and takes the next job as soon as it is done with one, so a
+// slow day doesn't hold up the others. Errors and panics only fail their own
+// day, see `runner::try_solve`.
+//
+pub fn solve_days(
+    jobs: &[Job],
+    config: &Config,
+    parts: &[Part],
+    threads: usize,
+) -> Vec<Result<DayResult, String>> {
+    solve_with(jobs, parts, threads, || {
+        crate::registries(config).expect("config of the run is valid")
+    })
+}
+
+// `solve_days` with the registries every thread gets from `registries`
+fn solve_with<F>(
+    jobs: &[Job],
+    parts: &[Part],
+    threads: usize,
+    registries: F,
+) -> Vec<Result<DayResult, String>>
+where
+    F: Fn() -> Vec<Registry> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<DayResult, String>>>> =
+        Mutex::new(jobs.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                let registries = registries();
+
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let job = match jobs.get(idx) {
+                        Some(job) => job,
+                        None => break,
+                    };
+
+                    let puzzle = registries
+                        .iter()
+                        .find(|registry| registry.year() == job.year)
+                        .and_then(|registry| registry.get(job.day))
+                        .expect("solved day is registered");
+                    let result = match &job.input {
+                        Ok(input) => runner::try_solve(job.year, puzzle, input, parts),
+                        Err(message) => Err(message.clone()),
+                    };
+
+                    let mut results = results.lock().unwrap_or_else(|err| err.into_inner());
+                    results[idx] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(|err| err.into_inner())
+        .into_iter()
+        .map(|result| result.expect("every job is solved"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use utils::{Error, Solution};
+
+    // Day of a year of its own that panics on every input
+    const PANIC_YEAR: u16 = 2100;
+
+    struct Panicky;
+
+    impl Solution for Panicky {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Panicky";
+
+        type Input = String;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &str) -> Result<String, Error> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part_one(&self, input: &String) -> Result<usize, Error> {
+            panic!("boom on {:?}", input)
+        }
+
+        fn part_two(&self, input: &String) -> Result<usize, Error> {
+            Ok(input.len())
+        }
+    }
+
+    fn registries() -> Vec<Registry> {
+        let mut panicky = Registry::new(PANIC_YEAR, Path::new("inputs"));
+        panicky.register(Panicky);
+
+        vec![aoc2018::registry(), panicky]
+    }
+
+    fn job(day: u8, input: &str) -> Job {
+        Job {
+            year: aoc2018::YEAR,
+            day,
+            input: Ok(input.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_solve_days() {
+        let jobs = vec![
+            job(5, "dabAcCaCBAcCcaDA\n"),
+            job(1, "+1\n-1\n"),
+            job(2, "abcdef\n"),
+            Job {
+                year: aoc2018::YEAR,
+                day: 3,
+                input: Err(String::from("3.txt not found")),
+            },
+            job(1, "x\n"),
+            Job {
+                year: PANIC_YEAR,
+                day: 1,
+                input: Ok(String::from("units\n")),
+            },
+            job(5, "aA\n"),
+        ];
+
+        for threads in [1, 4] {
+            let results = solve_with(&jobs, &[Part::One], threads, registries);
+            let answers: Vec<Result<String, String>> = results
+                .into_iter()
+                .map(|result| result.and_then(|result| result.parts[0].answer.clone()))
+                .collect();
+
+            assert_eq!(
+                answers,
+                vec![
+                    Ok(String::from("10")),
+                    Ok(String::from("0")),
+                    Ok(String::from("0")),
+                    Err(String::from("3.txt not found")),
+                    Err(String::from(
+                        "invalid input: line 1, column 1: expected a number, found \"x\""
+                    )),
+                    // the panic only fails its own day, the next one is solved
+                    Err(String::from("boom on \"units\"")),
+                    Ok(String::from("0")),
+                ],
+                "{} threads",
+                threads
+            );
+        }
+    }
+}
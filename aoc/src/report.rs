@@ -0,0 +1,536 @@
+use crate::fuzzer::{Failure, FuzzResult};
+use crate::runner::{BenchResult, DayResult, PartResult, Stats};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use utils::examples::ExampleResult;
+use utils::json::Json;
+use utils::ledger::{Ledger, Verdict};
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+
+    if micros == 0 {
+        format!("{}ns", duration.as_nanos())
+    } else if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+// Year, day, title and the result of solving it
+pub type DayRun<'a> = (u16, u8, &'a str, Result<DayResult, String>);
+
+// "2018/7"
+fn day_label(year: u16, day: u8) -> String {
+    format!("{}/{}", year, day)
+}
+
+// Answer of a part, or its error for the answer column of a table
+fn answer_cell(part: &PartResult) -> String {
+    match &part.answer {
+        Ok(answer) => answer.clone(),
+        Err(message) => format!("ERROR: {}", message),
+    }
+}
+
+// Days that failed get a row with their error, parts without an answer show
+// theirs in place of it. Returns how many days failed.
+pub fn print_results(runs: &[DayRun], wall: Duration, threads: usize) -> usize {
+    let mut failed = 0;
+
+    println!(
+        "{:>7}  {:<28}  {:>4}  {:<28}  {:>10}",
+        "Day", "Title", "Part", "Answer", "Time"
+    );
+
+    for (year, day, title, result) in runs {
+        let day = day_label(*year, *day);
+        let result = match result {
+            Ok(result) => result,
+            Err(message) => {
+                failed += 1;
+                println!("{:>7}  {:<28}  {:>4}  ERROR: {}", day, title, "-", message);
+                continue;
+            }
+        };
+        if result.failed() {
+            failed += 1;
+        }
+
+        println!(
+            "{:>7}  {:<28}  {:>4}  {:<28}  {:>10}",
+            day,
+            result.title,
+            "-",
+            "(parse)",
+            format_duration(result.parse)
+        );
+        for part in &result.parts {
+            println!(
+                "{:>7}  {:<28}  {:>4}  {:<28}  {:>10}",
+                day,
+                result.title,
+                part.part.number(),
+                answer_cell(part),
+                format_duration(part.elapsed)
+            );
+        }
+    }
+
+    // time of every day added up, wall is what the threads took together
+    let total: Duration = runs
+        .iter()
+        .filter_map(|(_, _, _, result)| result.as_ref().ok())
+        .map(|result| result.total())
+        .sum();
+    println!(
+        "Solved: {}, failed: {}, total: {}, wall: {} on {} thread{}",
+        runs.len() - failed,
+        failed,
+        format_duration(total),
+        format_duration(wall),
+        threads,
+        if threads == 1 { "" } else { "s" }
+    );
+
+    failed
+}
+
+fn diagnostics_json(part: &PartResult) -> Json {
+    Json::Object(
+        part.diagnostics
+            .iter()
+            .map(|(key, value)| (key.to_string(), Json::from(value.as_str())))
+            .collect(),
+    )
+}
+
+// One object per part, parse time is reported per day. A part without an
+// answer has a null one and an `error`.
+pub fn results_json(results: &[DayResult]) -> Json {
+    let rows: Vec<Json> = results
+        .iter()
+        .flat_map(|result| {
+            result.parts.iter().map(move |part| {
+                let row = Json::object()
+                    .with("year", result.year)
+                    .with("day", result.day)
+                    .with("title", result.title)
+                    .with("part", part.part.number());
+                let row = match &part.answer {
+                    Ok(answer) => row.with("answer", answer.as_str()),
+                    Err(message) => row
+                        .with("answer", Json::Null)
+                        .with("error", message.as_str()),
+                };
+
+                row.with("duration_ns", part.elapsed.as_nanos())
+                    .with("parse_ns", result.parse.as_nanos())
+                    .with("diagnostics", diagnostics_json(part))
+            })
+        })
+        .collect();
+    let total: Duration = results.iter().map(|result| result.total()).sum();
+
+    Json::object()
+        .with("results", rows)
+        .with("total_ns", total.as_nanos())
+}
+
+// Tabs and newlines would break the columns
+fn tsv_field(value: &str) -> String {
+    value.replace(['\t', '\n', '\r'], " ")
+}
+
+// Diagnostics go into a column as `key=value;key=value`, the last one holds
+// the error of a part without an answer
+pub fn results_tsv(results: &[DayResult]) -> String {
+    let mut out = String::from("year\tday\tpart\tanswer\tduration_ns\tdiagnostics\terror\n");
+
+    for result in results {
+        for part in &result.parts {
+            let diagnostics: Vec<String> = part
+                .diagnostics
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, tsv_field(value)))
+                .collect();
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (answer.as_str(), ""),
+                Err(message) => ("", message.as_str()),
+            };
+
+            out.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                result.year,
+                result.day,
+                part.part.number(),
+                tsv_field(answer),
+                part.elapsed.as_nanos(),
+                diagnostics.join(";"),
+                tsv_field(error)
+            ));
+        }
+    }
+
+    out
+}
+
+#[derive(Default)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+}
+
+// `ledgers` holds answers of every year, keyed by year
+pub fn print_test_results(results: &[DayRun], ledgers: &BTreeMap<u16, Ledger>) -> TestSummary {
+    let mut summary = TestSummary::default();
+    let empty = Ledger::new();
+
+    println!(
+        "{:>7}  {:<28}  {:>4}  {:<7}  {:<28}  Expected",
+        "Day", "Title", "Part", "Status", "Answer"
+    );
+
+    for (year, day, title, result) in results {
+        let label = day_label(*year, *day);
+        let ledger = ledgers.get(year).unwrap_or(&empty);
+        let result = match result {
+            Ok(result) => result,
+            Err(message) => {
+                summary.failed += 1;
+                println!(
+                    "{:>7}  {:<28}  {:>4}  {:<7}  {}",
+                    label, title, "-", "ERROR", message
+                );
+                continue;
+            }
+        };
+
+        for part in &result.parts {
+            let id = day.to_string();
+            let verdict = match &part.answer {
+                Ok(answer) => ledger.check(&id, part.part.number(), answer),
+                // no answer fails the part whatever the ledger expects
+                Err(_) => Verdict::Fail {
+                    expected: ledger
+                        .expected(&id, part.part.number())
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+            };
+            let status = match (&part.answer, &verdict) {
+                (Err(_), _) => "ERROR",
+                (_, Verdict::Pass) => "PASS",
+                (_, Verdict::Fail { .. }) => "FAIL",
+                (_, Verdict::Missing) => "MISSING",
+            };
+
+            let expected = match verdict {
+                Verdict::Pass => {
+                    summary.passed += 1;
+                    String::new()
+                }
+                Verdict::Fail { expected } => {
+                    summary.failed += 1;
+                    expected
+                }
+                Verdict::Missing => {
+                    summary.missing += 1;
+                    String::new()
+                }
+            };
+
+            let row = format!(
+                "{:>7}  {:<28}  {:>4}  {:<7}  {:<28}  {}",
+                label,
+                title,
+                part.part.number(),
+                status,
+                part.answer.as_ref().unwrap_or_else(|message| message),
+                expected
+            );
+            println!("{}", row.trim_end());
+        }
+    }
+
+    println!(
+        "Passed: {}, failed: {}, missing: {}",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    summary
+}
+
+// Year, example id, day title and results of its parts
+pub type ExampleRun<'a> = (u16, String, &'a str, Result<Vec<ExampleResult>, String>);
+
+pub fn print_example_results(results: &[ExampleRun]) -> TestSummary {
+    let mut summary = TestSummary::default();
+
+    println!(
+        "{:<10}  {:<28}  {:>4}  {:<7}  {:<28}  Expected",
+        "Example", "Title", "Part", "Status", "Answer"
+    );
+
+    for (year, id, title, results) in results {
+        let id = format!("{}/{}", year, id);
+        let results = match results {
+            Ok(results) => results,
+            Err(message) => {
+                summary.failed += 1;
+                println!(
+                    "{:<10}  {:<28}  {:>4}  {:<7}  {}",
+                    id, title, "-", "ERROR", message
+                );
+                continue;
+            }
+        };
+
+        for result in results {
+            let (status, expected) = match &result.verdict {
+                Verdict::Pass => {
+                    summary.passed += 1;
+                    ("PASS", "")
+                }
+                Verdict::Fail { expected } => {
+                    summary.failed += 1;
+                    ("FAIL", expected.as_str())
+                }
+                Verdict::Missing => {
+                    summary.missing += 1;
+                    ("MISSING", "")
+                }
+            };
+
+            let row = format!(
+                "{:<10}  {:<28}  {:>4}  {:<7}  {:<28}  {}",
+                id, title, result.part, status, result.answer, expected
+            );
+            println!("{}", row.trim_end());
+        }
+    }
+
+    println!(
+        "Passed: {}, failed: {}, missing: {}",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    summary
+}
+
+pub fn print_bench_results(results: &[BenchResult]) {
+    println!(
+        "{:>7}  {:<28}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Title", "Phase", "Min", "Median", "Max"
+    );
+
+    for result in results {
+        let phases = std::iter::once((String::from("parse"), &result.parse)).chain(
+            result
+                .parts
+                .iter()
+                .map(|(part, stats)| (format!("part {}", part.number()), stats)),
+        );
+
+        for (phase, stats) in phases {
+            println!(
+                "{:>7}  {:<28}  {:<6}  {:>10}  {:>10}  {:>10}",
+                day_label(result.year, result.day),
+                result.title,
+                phase,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+        }
+    }
+
+    if let Some(result) = results.first() {
+        println!(
+            "Iterations: {}, warmup: {}",
+            result.iterations, result.warmup
+        );
+    }
+}
+
+// Inputs tried per day, then every finding with its input. Returns the
+// number of findings.
+pub fn print_fuzz_results(results: &[FuzzResult]) -> usize {
+    println!(
+        "{:>7}  {:<28}  {:>7}  {:>7}  {:>8}",
+        "Day", "Title", "Inputs", "Parsed", "Findings"
+    );
+
+    for result in results {
+        println!(
+            "{:>7}  {:<28}  {:>7}  {:>7}  {:>8}",
+            day_label(result.year, result.day),
+            result.title,
+            result.inputs,
+            result.parsed,
+            result.findings.len()
+        );
+    }
+
+    let mut found = 0;
+    for result in results {
+        for finding in &result.findings {
+            found += 1;
+            match &finding.failure {
+                Failure::Panic { message, location } => println!(
+                    "\n{} panicked at {}: {}",
+                    day_label(result.year, result.day),
+                    location,
+                    message
+                ),
+                Failure::Hang => println!(
+                    "\n{} did not finish in time",
+                    day_label(result.year, result.day)
+                ),
+            }
+            println!("input ({} bytes): {:?}", finding.input.len(), finding.input);
+        }
+    }
+
+    println!("Findings: {}", found);
+    found
+}
+
+fn stats_json(stats: &Stats) -> Json {
+    Json::object()
+        .with("min_ns", stats.min.as_nanos())
+        .with("median_ns", stats.median.as_nanos())
+        .with("max_ns", stats.max.as_nanos())
+}
+
+pub fn bench_results_json(results: &[BenchResult]) -> Json {
+    let days: Vec<Json> = results
+        .iter()
+        .map(|result| {
+            let mut json = Json::object()
+                .with("year", result.year)
+                .with("day", result.day)
+                .with("title", result.title)
+                .with("iterations", result.iterations)
+                .with("warmup", result.warmup)
+                .with("parse", stats_json(&result.parse));
+
+            for (part, stats) in &result.parts {
+                json = json.with(&format!("part{}", part.number()), stats_json(stats));
+            }
+
+            json
+        })
+        .collect();
+
+    Json::object().with("bench", days)
+}
+
+pub fn bench_results_tsv(results: &[BenchResult]) -> String {
+    let mut out = String::from("year\tday\tphase\tmin_ns\tmedian_ns\tmax_ns\n");
+
+    for result in results {
+        let phases = std::iter::once((String::from("parse"), &result.parse)).chain(
+            result
+                .parts
+                .iter()
+                .map(|(part, stats)| (format!("part{}", part.number()), stats)),
+        );
+
+        for (phase, stats) in phases {
+            out.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\n",
+                result.year,
+                result.day,
+                phase,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Part;
+
+    fn day_result() -> DayResult {
+        DayResult {
+            year: 2018,
+            day: 5,
+            title: "Alchemical Reduction",
+            parse: Duration::from_nanos(100),
+            parts: vec![PartResult {
+                part: Part::Two,
+                answer: Ok(String::from("4")),
+                diagnostics: vec![("removed", String::from("c"))],
+                elapsed: Duration::from_nanos(250),
+            }],
+        }
+    }
+
+    // Part one answered, part two without an answer
+    fn unanswered_result() -> DayResult {
+        DayResult {
+            year: 2018,
+            day: 1,
+            title: "Chronal Calibration",
+            parse: Duration::from_nanos(10),
+            parts: vec![
+                PartResult {
+                    part: Part::One,
+                    answer: Ok(String::from("1")),
+                    diagnostics: vec![],
+                    elapsed: Duration::from_nanos(20),
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: Err(String::from("no answer: no frequency is reached twice")),
+                    diagnostics: vec![],
+                    elapsed: Duration::from_nanos(30),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_results_tsv() {
+        assert_eq!(
+            results_tsv(&[day_result()]),
+            "year\tday\tpart\tanswer\tduration_ns\tdiagnostics\terror\n2018\t5\t2\t4\t250\tremoved=c\t\n"
+        );
+        assert_eq!(
+            results_tsv(&[unanswered_result()]),
+            concat!(
+                "year\tday\tpart\tanswer\tduration_ns\tdiagnostics\terror\n",
+                "2018\t1\t1\t1\t20\t\t\n",
+                "2018\t1\t2\t\t30\t\tno answer: no frequency is reached twice\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_results_json() {
+        assert_eq!(
+            results_json(&[day_result()]).to_string(),
+            concat!(
+                r#"{"results":[{"year":2018,"day":5,"title":"Alchemical Reduction","part":2,"answer":"4","#,
+                r#""duration_ns":250,"parse_ns":100,"diagnostics":{"removed":"c"}}],"total_ns":350}"#
+            )
+        );
+
+        let unanswered = results_json(&[unanswered_result()]).to_string();
+        assert!(unanswered.contains(r#""part":1,"answer":"1","#));
+        assert!(unanswered.contains(
+            r#""part":2,"answer":null,"error":"no answer: no frequency is reached twice","#
+        ));
+    }
+}
@@ -0,0 +1,268 @@
+use crate::cli::Part;
+use std::any::Any;
+use std::panic;
+use std::time::{Duration, Instant};
+use utils::{Canvas, Error, Image, Puzzle};
+
+pub struct PartResult {
+    pub part: Part,
+    // the error of a part without an answer, the other part is still solved
+    pub answer: Result<String, String>,
+    pub diagnostics: Vec<(&'static str, String)>,
+    pub elapsed: Duration,
+}
+
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn total(&self) -> Duration {
+        self.parts
+            .iter()
+            .fold(self.parse, |acc, part| acc + part.elapsed)
+    }
+
+    // Some part has no answer
+    pub fn failed(&self) -> bool {
+        self.parts.iter().any(|part| part.answer.is_err())
+    }
+}
+
+// An input that can't be parsed fails the whole day, a part without an
+// answer only itself
+pub fn solve(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+) -> Result<DayResult, Error> {
+    let started = Instant::now();
+    let prepared = puzzle.prepare(input)?;
+    let parse = started.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let started = Instant::now();
+            let output = match part {
+                Part::One => prepared.part_one(),
+                Part::Two => prepared.part_two(),
+            };
+            let elapsed = started.elapsed();
+
+            let (answer, diagnostics) = match output {
+                Ok(output) => (Ok(output.answer), output.diagnostics),
+                Err(err) => (Err(err.to_string()), vec![]),
+            };
+            PartResult {
+                part: *part,
+                answer,
+                diagnostics,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        year,
+        day: puzzle.day(),
+        title: puzzle.title(),
+        parse,
+        parts,
+    })
+}
+
+// Draws the puzzle state of the day on `canvas`, nothing for invalid inputs
+pub fn visualize(puzzle: &dyn Puzzle, input: &str, canvas: &mut Canvas) {
+    if let Ok(prepared) = puzzle.prepare(input) {
+        prepared.visualize(canvas);
+    }
+}
+
+// Named maps of the puzzle state of the day
+pub fn images(puzzle: &dyn Puzzle, input: &str) -> Vec<(&'static str, Image)> {
+    puzzle
+        .prepare(input)
+        .map_or(vec![], |prepared| prepared.images())
+}
+
+// Runs `f` turning a panic into its message
+pub fn catch<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    panic::catch_unwind(panic::AssertUnwindSafe(f))
+        .map_err(|payload| panic_message(payload.as_ref()))
+}
+
+// Same as `solve` but errors and panicking days are reported as a message
+pub fn try_solve(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+) -> Result<DayResult, String> {
+    catch(|| solve(year, puzzle, input, parts))?.map_err(|err| err.to_string())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+// Spread of timings over benchmark iterations
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_timings(mut timings: Vec<Duration>) -> Stats {
+        timings.sort();
+
+        Stats {
+            min: timings.first().cloned().unwrap_or_default(),
+            median: timings.get(timings.len() / 2).cloned().unwrap_or_default(),
+            max: timings.last().cloned().unwrap_or_default(),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+//
+// Parses the input and solves each part `iterations` times after `warmup`
+// untimed rounds, timing every phase separately.
+//
+pub fn bench(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+    warmup: usize,
+) -> Result<BenchResult, Error> {
+    for _ in 0..warmup {
+        solve(year, puzzle, input, parts)?;
+    }
+
+    let runs = (0..iterations)
+        .map(|_| solve(year, puzzle, input, parts))
+        .collect::<Result<Vec<DayResult>, Error>>()?;
+
+    let parse = Stats::from_timings(runs.iter().map(|run| run.parse).collect());
+    let parts = parts
+        .iter()
+        .enumerate()
+        .map(|(idx, part)| {
+            let timings = runs.iter().map(|run| run.parts[idx].elapsed).collect();
+            (*part, Stats::from_timings(timings))
+        })
+        .collect();
+
+    Ok(BenchResult {
+        year,
+        day: puzzle.day(),
+        title: puzzle.title(),
+        iterations,
+        warmup,
+        parse,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Config;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 7), Ok(7));
+        assert_eq!(catch(|| panic!("boom")), Err::<(), _>(String::from("boom")));
+        assert_eq!(
+            catch(|| panic!("boom on day {}", 5)),
+            Err::<(), _>(String::from("boom on day 5"))
+        );
+    }
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&String::from("boom")), "boom");
+        assert_eq!(panic_message(&42), "unknown panic");
+    }
+
+    // Parameters too big to solve with are config errors before any day runs,
+    // an allocation failure would abort instead of unwinding
+    #[test]
+    fn test_oversized_parameters() {
+        for (assignment, error) in [
+            (
+                "2018.7.workers=100000000000",
+                "[2018.7] workers: expected from 1 to 26, found 100000000000",
+            ),
+            (
+                "2018.7.base_seconds=100000000",
+                "[2018.7] base_seconds: expected from 0 to 3600, found 100000000",
+            ),
+            (
+                "2018.3.fabric_size=10000000",
+                "[2018.3] fabric_size: expected from 2 to 5000, found 10000000",
+            ),
+        ] {
+            let mut config = Config::new();
+            config.set(assignment).unwrap();
+            let err = crate::registries(&config).err().map(|err| err.to_string());
+            assert_eq!(err.as_deref(), Some(error), "{}", assignment);
+        }
+
+        // the largest ones still answer, C and A take 3603 and 3601 seconds
+        let mut config = Config::new();
+        config.set("2018.7.workers=26").unwrap();
+        config.set("2018.7.base_seconds=3600").unwrap();
+        let registries = crate::registries(&config).unwrap();
+        let puzzle = registries[0].get(7).unwrap();
+
+        let input = "Step C must be finished before step A can begin.\n";
+        let result = try_solve(2018, puzzle, input, &[Part::Two]).unwrap();
+        assert_eq!(result.parts[0].answer, Ok(String::from("7204")));
+    }
+
+    #[test]
+    fn test_try_solve() {
+        let registry = aoc2018::registry();
+        let puzzle = registry.get(1).unwrap();
+
+        let result = try_solve(2018, puzzle, "+1\n", &[Part::One, Part::Two]).unwrap();
+        assert_eq!(result.parts[0].answer, Ok(String::from("1")));
+        assert_eq!(
+            result.parts[1].answer,
+            Err(String::from("no answer: no frequency is reached twice"))
+        );
+        assert!(result.failed());
+
+        assert_eq!(
+            try_solve(2018, puzzle, "x\n", &[Part::One]).err(),
+            Some(String::from(
+                "invalid input: line 1, column 1: expected a number, found \"x\""
+            ))
+        );
+    }
+}
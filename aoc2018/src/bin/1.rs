@@ -0,0 +1,20 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day1::Day1;
+use utils::Solution;
+
+fn main() {
+    let changes = aoc2018::load_from_args(&Day1);
+
+    println!("--- Part 1 ---");
+    println!(
+        "Sum of freq adjustments: {}",
+        aoc2018::answer_or_exit(Day1.part_one(&changes))
+    );
+    println!("--- Part 2 ---");
+    println!(
+        "Repeating frequency: {}",
+        aoc2018::answer_or_exit(Day1.part_two(&changes))
+    );
+}
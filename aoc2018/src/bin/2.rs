@@ -0,0 +1,16 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day2::Day2;
+use utils::Solution;
+
+fn main() {
+    let ids = aoc2018::load_from_args(&Day2);
+
+    println!("{}", aoc2018::answer_or_exit(Day2.part_one(&ids)));
+    println!("{}", "-".repeat(100));
+    println!(
+        "Common letters: {}",
+        aoc2018::answer_or_exit(Day2.part_two(&ids))
+    );
+}
@@ -0,0 +1,19 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day3::Day3;
+use utils::Solution;
+
+fn main() {
+    let day3 = aoc2018::configured::<Day3>();
+    let claims = aoc2018::load_from_args(&day3);
+
+    println!(
+        "Total inches of fabric within 2 or more claims: {}",
+        aoc2018::answer_or_exit(day3.part_one(&claims))
+    );
+    println!(
+        "Non overlaping claim: {}",
+        aoc2018::answer_or_exit(day3.part_two(&claims))
+    );
+}
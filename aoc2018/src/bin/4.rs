@@ -0,0 +1,23 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day4::Day4;
+use utils::Solution;
+
+fn main() {
+    let actions = aoc2018::load_from_args(&Day4);
+
+    let sleephead = aoc2018::answer_or_exit(Day4.part_one(&actions));
+    println!("--- Part 1 ---");
+    println!(
+        "Guard #{} sleeps most on minute {}, result: {}",
+        sleephead.guard, sleephead.minute, sleephead
+    );
+
+    let sleephead = aoc2018::answer_or_exit(Day4.part_two(&actions));
+    println!("--- Part 2 ---");
+    println!(
+        "Guard #{} is most frequently asleep on minute {}, solution: {}",
+        sleephead.guard, sleephead.minute, sleephead
+    );
+}
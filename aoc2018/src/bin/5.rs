@@ -0,0 +1,22 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day5::Day5;
+use utils::Solution;
+
+fn main() {
+    let day5 = aoc2018::configured::<Day5>();
+    let polymer = aoc2018::load_from_args(&day5);
+
+    println!("--- Part 1 ---");
+    println!(
+        "Final length: {}",
+        aoc2018::answer_or_exit(day5.part_one(&polymer))
+    );
+    println!("--- Part 2 ---");
+    let shortest = aoc2018::answer_or_exit(day5.part_two(&polymer));
+    match shortest.removed {
+        Some(unit) => println!("Min len: {} (without {})", shortest.length, unit),
+        None => println!("Min len: {}", shortest.length),
+    }
+}
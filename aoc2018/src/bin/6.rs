@@ -0,0 +1,22 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day6::Day6;
+use utils::Solution;
+
+fn main() {
+    let day6 = aoc2018::configured::<Day6>();
+    let coordinates = aoc2018::load_from_args(&day6);
+
+    println!("--- Part 1 ---");
+    let island = aoc2018::answer_or_exit(day6.part_one(&coordinates));
+    println!(
+        "Biggest island size: {} (point {})",
+        island.size, island.point
+    );
+    println!("--- Part 2 ---");
+    println!(
+        "Closest island size: {:?}",
+        aoc2018::answer_or_exit(day6.part_two(&coordinates))
+    );
+}
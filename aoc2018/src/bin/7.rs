@@ -0,0 +1,20 @@
+extern crate aoc2018;
+extern crate utils;
+
+use aoc2018::day7::Day7;
+use utils::Solution;
+
+fn main() {
+    let day7 = aoc2018::configured::<Day7>();
+    let dag = aoc2018::load_from_args(&day7);
+
+    println!(
+        "DAG Sorted: {}",
+        aoc2018::answer_or_exit(day7.part_one(&dag))
+    );
+    let assembly = aoc2018::answer_or_exit(day7.part_two(&dag));
+    println!(
+        "Assembly done in: {} seconds by {} workers ({})",
+        assembly.seconds, assembly.workers, assembly.order
+    );
+}
@@ -0,0 +1,263 @@
+// https://adventofcode.com/2018/day/1
+//
+// After feeling like you've been falling for a few minutes, you look at the
+// device's tiny screen. "Error: Device must be calibrated before first use.
+// Frequency drift detected. Cannot maintain destination lock." Below the
+// message, the device shows a sequence of changes in frequency (your puzzle
+// input). A value like +6 means the current frequency increases by 6; a value
+// like -3 means the current frequency decreases by 3.
+//
+// For example, if the device displays frequency changes of +1, -2, +3, +1, then
+// starting from a frequency of zero, the following changes would occur:
+//
+// Current frequency  0, change of +1; resulting frequency  1.
+// Current frequency  1, change of -2; resulting frequency -1.
+// Current frequency -1, change of +3; resulting frequency  2.
+// Current frequency  2, change of +1; resulting frequency  3.
+// In this example, the resulting frequency is 3.
+//
+// Here are other example situations:
+//
+// +1, +1, +1 results in  3
+// +1, +1, -2 results in  0
+// -1, -2, -3 results in -6
+//
+// Starting with a frequency of zero, what is the resulting frequency after all
+// of the changes in frequency have been applied?
+//
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use utils::parse::Scanner;
+use utils::{parse_lines, ConfigError, Configure, Error, Params, ParseError, Rng, Solution};
+
+// +6 or -3
+struct Change(i32);
+
+impl FromStr for Change {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Change, ParseError> {
+        let mut scanner = Scanner::new(line);
+
+        let change = scanner.number()?;
+        scanner.end()?;
+
+        Ok(Change(change))
+    }
+}
+
+/// Day 1 for the runner: the input is the list of frequency changes
+pub struct Day1;
+
+// Nothing in the puzzle to configure
+impl Configure for Day1 {
+    fn configure(_params: &mut Params) -> Result<Day1, ConfigError> {
+        Ok(Day1)
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Chronal Calibration";
+
+    // frequencies add up beyond i32, every change fits one
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, Error> {
+        Ok(parse_lines::<Change>(input)?
+            .into_iter()
+            .map(|Change(change)| change as i64)
+            .collect())
+    }
+
+    fn part_one(&self, changes: &Vec<i64>) -> Result<i64, Error> {
+        Ok(resulting_frequency(changes))
+    }
+
+    // changes that only drift away never repeat a frequency
+    fn part_two(&self, changes: &Vec<i64>) -> Result<i64, Error> {
+        first_repeating_frequency(changes)
+            .ok_or_else(|| Error::no_answer("no frequency is reached twice"))
+    }
+
+    // Changes of up to 20 either way, about 1000 of them. Their sum is kept
+    // below their count, so two frequencies of the first round are equal
+    // modulo the sum and part two always finds a repeat.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(1000).max(1) as i64;
+
+        let mut changes: Vec<i64> = (1..count)
+            .map(|_| rng.between(1, 20) * if rng.chance(0.5) { 1 } else { -1 })
+            .collect();
+        let sum = rng.between(1 - count, count - 1);
+        changes.push(sum - changes.iter().sum::<i64>());
+        rng.shuffle(&mut changes);
+
+        Some(
+            changes
+                .iter()
+                .map(|change| format!("{:+}\n", change))
+                .collect(),
+        )
+    }
+}
+
+// --- Part Two ---
+//
+// You notice that the device repeats the same frequency change list over and
+// over. To calibrate the device, you need to find the first frequency it
+// reaches twice.
+//
+// For example, using the same list of changes above, the device would loop as
+// follows:
+//
+// Current frequency  0, change of +1; resulting frequency  1.
+// Current frequency  1, change of -2; resulting frequency -1.
+// Current frequency -1, change of +3; resulting frequency  2.
+// Current frequency  2, change of +1; resulting frequency  3.
+// (At this point, the device continues from the start of the list.)
+// Current frequency  3, change of +1; resulting frequency  4.
+// Current frequency  4, change of -2; resulting frequency  2, which has already been seen.
+//
+// In this example, the first frequency reached twice is 2. Note that your
+// device might need to repeat its list of frequency changes many times before a
+// duplicate frequency is found, and that duplicates might be found while in the
+// middle of processing the list.
+//
+// Here are other examples:
+//
+// +1, -1 first reaches 0 twice.
+// +3, +3, +4, -2, -4 first reaches 10 twice.
+// -6, +3, +8, +5, -6 first reaches 5 twice.
+// +7, +7, -2, -7, -4 first reaches 14 twice.
+
+/// Frequency after applying every change once, starting from zero
+pub fn resulting_frequency(changes: &[i64]) -> i64 {
+    changes.iter().sum()
+}
+
+/// First frequency reached twice while the changes are applied over and over,
+/// None when there is no repeat at all.
+///
+/// The first round is walked frequency by frequency. After that every round
+/// shifts the frequencies of the first one by the same drift, so a frequency f
+/// of the first round reaches a later one g after (g - f) / drift rounds if they
+/// are equal modulo the drift, and never otherwise. The repeat is the earliest
+/// of these, found without walking what may be millions of rounds.
+pub fn first_repeating_frequency(changes: &[i64]) -> Option<i64> {
+    let mut result: i64 = 0;
+    let mut seen_freqs: HashSet<i64> = HashSet::new();
+    // frequency before each change of the first round
+    let mut first_round = Vec::with_capacity(changes.len());
+
+    seen_freqs.insert(result);
+
+    for num in changes {
+        first_round.push(result);
+        result += num;
+
+        if !seen_freqs.insert(result) {
+            return Some(result);
+        }
+    }
+
+    let drift = result;
+    if drift == 0 {
+        return None; // no changes at all
+    }
+
+    let mut classes: HashMap<i64, Vec<(i64, usize)>> = HashMap::new();
+    for (idx, freq) in first_round.into_iter().enumerate() {
+        classes
+            .entry(freq.rem_euclid(drift.abs()))
+            .or_default()
+            .push((freq, idx));
+    }
+
+    // (changes applied, frequency) of the first repeat in every class
+    let per_round = changes.len() as i64;
+    let mut repeats = vec![];
+    for class in classes.values_mut() {
+        // in the order frequencies drift through
+        class.sort_by_key(|&(freq, _)| freq * drift.signum());
+
+        for pair in class.windows(2) {
+            let ((from, idx), (to, _)) = (pair[0], pair[1]);
+            repeats.push(((to - from) / drift * per_round + idx as i64, to));
+        }
+    }
+
+    repeats.into_iter().min().map(|(_, freq)| freq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::property;
+
+    // Applies the changes over and over like the description, gives up after
+    // `limit` of them
+    fn walk_until_repeat(changes: &[i64], limit: usize) -> Option<i64> {
+        let mut frequency = 0;
+        let mut seen = HashSet::from([frequency]);
+
+        for change in changes.iter().cycle().take(limit) {
+            frequency += change;
+            if !seen.insert(frequency) {
+                return Some(frequency);
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn test_first_repeating_frequency() {
+        assert_eq!(first_repeating_frequency(&[1, -1]), Some(0));
+        assert_eq!(first_repeating_frequency(&[3, 3, 4, -2, -4]), Some(10));
+        assert_eq!(first_repeating_frequency(&[-6, 3, 8, 5, -6]), Some(5));
+        assert_eq!(first_repeating_frequency(&[7, 7, -2, -7, -4]), Some(14));
+    }
+
+    #[test]
+    fn test_no_repeat() {
+        // no drift and no repeat only happens without changes
+        assert_eq!(first_repeating_frequency(&[]), None);
+        // frequencies of a round all in classes of their own
+        assert_eq!(first_repeating_frequency(&[1, 1]), None);
+        assert_eq!(first_repeating_frequency(&[1_000_000]), None);
+    }
+
+    #[test]
+    fn test_large_drift() {
+        // half a million rounds until 0 drifts up to 1000000
+        assert_eq!(
+            first_repeating_frequency(&[1_000_000, -999_998]),
+            Some(1_000_000)
+        );
+        // a million rounds the other way
+        assert_eq!(
+            first_repeating_frequency(&[-1_000_000, 999_999]),
+            Some(-1_000_000)
+        );
+        assert_eq!(
+            walk_until_repeat(&[1_000_000, -999_998], 2_000_000),
+            Some(1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_same_repeat_as_walking() {
+        let changes = |rng: &mut Rng, size| property::vec_of(rng, size, |rng| rng.between(-9, 9));
+
+        property::check("walking", changes, |changes: &Vec<i64>| {
+            // a repeat past the walk can't be checked this way
+            match walk_until_repeat(changes, 100_000) {
+                Some(walked) => first_repeating_frequency(changes) == Some(walked),
+                None => true,
+            }
+        });
+    }
+}
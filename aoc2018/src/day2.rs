@@ -43,34 +43,110 @@
 
 // What is the checksum for your list of box IDs?
 
-extern crate utils;
 use std::collections::HashMap;
+use std::str::FromStr;
+use utils::parse::Scanner;
+use utils::{
+    parse_lines, ConfigError, Configure, Counter, Error, Params, ParseError, Rng, Solution,
+};
+
+// abcdef, lowercase letters only
+struct BoxId(String);
+
+impl FromStr for BoxId {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<BoxId, ParseError> {
+        let mut scanner = Scanner::new(line);
+        scanner.skip_whitespace();
+
+        let id = scanner.rest().trim_end();
+        match id.find(|chr: char| !chr.is_ascii_lowercase()) {
+            Some(len) => {
+                scanner.take_until(&id[len..])?;
+                Err(scanner.error("a lowercase letter"))
+            }
+            None => Ok(BoxId(id.to_string())),
+        }
+    }
+}
+
+/// Day 2 for the runner: the input is the list of box ids
+pub struct Day2;
+
+impl Configure for Day2 {
+    fn configure(_params: &mut Params) -> Result<Day2, ConfigError> {
+        Ok(Day2)
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Inventory Management System";
 
-fn part_one() {
-    let input = utils::read_puzzle_input(2);
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_lines(input)?
+            .into_iter()
+            .map(|BoxId(id)| id)
+            .collect())
+    }
+
+    fn part_one(&self, ids: &Vec<String>) -> Result<u32, Error> {
+        Ok(checksum(ids))
+    }
+
+    fn part_two(&self, ids: &Vec<String>) -> Result<String, Error> {
+        common_letters(ids).ok_or_else(|| Error::no_answer("no two ids differ by one letter"))
+    }
+
+    // Ids of 26 random letters, so most of them repeat some letter, and a copy
+    // of one of them with a single letter changed for part two. Two random ids
+    // differing in one letter are unlikely enough to be ignored.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(250).max(2);
+
+        let mut ids: Vec<Vec<char>> = (1..count)
+            .map(|_| (0..26).map(|_| rng.letter()).collect())
+            .collect();
+
+        let mut close = rng.choose(&ids).unwrap().clone();
+        let idx = rng.below(close.len());
+        let shift = 1 + rng.below(25) as u8;
+        close[idx] = (b'a' + (close[idx] as u8 - b'a' + shift) % 26) as char;
+        ids.push(close);
+        rng.shuffle(&mut ids);
+
+        Some(
+            ids.into_iter()
+                .map(|id| id.into_iter().chain(Some('\n')).collect::<String>())
+                .collect(),
+        )
+    }
+}
+
+/// Ids with some letter exactly twice times ids with some letter exactly
+/// three times
+pub fn checksum(ids: &[String]) -> u32 {
     let mut seen_two_letters_count = 0;
     let mut seen_three_letters_count = 0;
 
-    for line in input.lines() {
-        let mut counter: HashMap<char, u8> = HashMap::new();
-
-        for chr in line.chars() {
-            counter
-                .entry(chr)
-                .and_modify(|count| *count += 1)
-                .or_insert(1);
-        }
+    for line in ids {
+        let counter: Counter<char> = line.chars().collect();
 
-        if counter.values().find(|v| **v == 2).is_some() {
+        if counter.iter().any(|(_, count)| count == 2) {
             seen_two_letters_count += 1;
         }
 
-        if counter.values().find(|v| **v == 3).is_some() {
+        if counter.iter().any(|(_, count)| count == 3) {
             seen_three_letters_count += 1;
         }
     }
 
-    println!("{}", seen_three_letters_count * seen_two_letters_count)
+    seen_three_letters_count * seen_two_letters_count
 }
 
 // Confident that your list of box IDs is complete, you're ready to find the
@@ -95,21 +171,17 @@ fn part_one() {
 // above, this is found by removing the differing character from either ID,
 // producing fgij.)
 
-use std::char;
-
-fn part_two() {
-    let input = utils::read_puzzle_input(2);
+/// Letters two ids differing in exactly one position have in common, the
+/// first such pair in alphabetical order if there are several.
+///
+/// Every id is expanded into its variants with one letter masked by '0', two
+/// ids that differ by exactly one letter share a masked variant. The common
+/// letters are that variant with the mask removed.
+pub fn common_letters(ids: &[String]) -> Option<String> {
     let mut results: HashMap<String, Vec<_>> = HashMap::new();
-    let mut lines_count = 0;
-    let mut ids_count = 0;
-
-    for id in input.lines() {
-        let ids = all_variants_with_1_letter_replaced(id);
 
-        lines_count += 1;
-
-        for id_changed in ids {
-            ids_count += 1;
+    for id in ids {
+        for id_changed in all_variants_with_1_letter_replaced(id) {
             results
                 .entry(id_changed)
                 .and_modify(|vec| vec.push(id))
@@ -117,29 +189,17 @@ fn part_two() {
         }
     }
 
-    let mut res = vec![];
+    let mut matches: Vec<&String> = results
+        .iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(matcher, _)| matcher)
+        .collect();
 
-    for (matcher, ids) in &results {
-        if ids.len() > 1 {
-            res.push((matcher.clone(), ids.clone()));
-        }
-    }
-
-    println!("{}", "-".repeat(100));
-
-    for tup in &res {
-        println!("{:?}", tup);
-    }
+    matches.sort();
 
-    println!("{}", "-".repeat(100));
-
-    println!("Res.len: {:?}", res.len());
-    println!("Keys: {}", results.keys().len());
-    println!(
-        "Values: {}",
-        results.values().fold(0, |acc, arr| acc + arr.len())
-    );
-    println!("Lines: {}, ids: {}", lines_count, ids_count);
+    matches
+        .first()
+        .map(|matcher| matcher.chars().filter(|c| *c != '0').collect())
 }
 
 fn all_variants_with_1_letter_replaced(id: &str) -> Vec<String> {
@@ -153,10 +213,5 @@ fn all_variants_with_1_letter_replaced(id: &str) -> Vec<String> {
         result.push(cur_map.into_iter().collect());
     }
 
-    return result;
-}
-
-fn main() {
-    part_one();
-    part_two();
+    result
 }
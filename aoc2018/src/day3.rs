@@ -0,0 +1,540 @@
+// --- Day 3: No Matter How You Slice It ---
+// The Elves managed to locate the chimney-squeeze prototype fabric for Santa's
+// suit (thanks to someone who helpfully wrote its box IDs on the wall of the
+// warehouse in the middle of the night). Unfortunately, anomalies are still
+// affecting them - nobody can even agree on how to cut the fabric.
+//
+// The whole piece of fabric they're working on is a very large square - at
+// least 1000 inches on each side.
+//
+// Each Elf has made a claim about which area of fabric would be ideal for
+// Santa's suit. All claims have an ID and consist of a single rectangle with
+// edges parallel to the edges of the fabric. Each claim's rectangle is defined
+// as follows:
+//
+// - The number of inches between the left edge of the fabric and the left edge of the rectangle.
+// - The number of inches between the top edge of the fabric and the top edge of the rectangle.
+// - The width of the rectangle in inches.
+// - The height of the rectangle in inches.
+//
+// A claim like #123 @ 3,2: 5x4 means that claim ID 123 specifies a rectangle 3
+// inches from the left edge, 2 inches from the top edge, 5 inches wide, and 4
+// inches tall. Visually, it claims the square inches of fabric represented by #
+// (and ignores the square inches of fabric represented by .) in the diagram
+// below:
+//
+// ...........
+// ...........
+// ...#####...
+// ...#####...
+// ...#####...
+// ...#####...
+// ...........
+// ...........
+// ...........
+//
+// The problem is that many of the claims overlap, causing two or more claims to
+// cover part of the same areas. For example, consider the following claims:
+//
+// #1 @ 1,3: 4x4
+// #2 @ 3,1: 4x4
+// #3 @ 5,5: 2x2
+// Visually, these claim the following areas:
+//
+// ........
+// ...2222.
+// ...2222.
+// .11XX22.
+// .11XX22.
+// .111133.
+// .111133.
+// ........
+//
+// The four square inches marked with X are claimed by both 1 and 2. (Claim 3,
+// while adjacent to the others, does not overlap either of them.)
+//
+// If the Elves all proceed with their own plans, none of them will have enough
+// fabric. How many square inches of fabric are within two or more claims?
+
+use std::str::FromStr;
+use utils::config::Value;
+use utils::image::{self, Image};
+use utils::parse::{self, Scanner};
+use utils::{
+    Canvas, Color, ConfigError, Configure, Error, Grid, Params, ParseError, Point2, Rect, Render,
+    Rng, Solution,
+};
+
+/// Side of the square piece of fabric of the puzzle, in inches
+pub const FABRIC_SIZE: usize = 1000;
+
+/// Largest `fabric_size` of the config, its map takes 100MB
+pub const MAX_FABRIC_SIZE: usize = 5000;
+
+// The square of fabric
+fn fabric(size: usize) -> Rect<i64> {
+    Rect::from_size(Point2::new(0, 0), size as i64, size as i64)
+}
+
+/// Elf's claim on a rectangle of the fabric
+#[derive(Debug)]
+pub struct Claim {
+    id: u32,
+    area: Rect<i64>,
+}
+
+impl Claim {
+    /// None unless the area lies within a fabric of `fabric_size` inches
+    pub fn new(id: u32, area: Rect<i64>, fabric_size: usize) -> Option<Claim> {
+        if !area.is_empty() && fabric(fabric_size).intersection(&area) == Some(area) {
+            Some(Claim { id, area })
+        } else {
+            None
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Inches claimed, `max` is just past the bottom right one
+    pub fn area(&self) -> &Rect<i64> {
+        &self.area
+    }
+}
+
+// #123 @ 3,2: 5x4, on the fabric of the puzzle
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Claim, ParseError> {
+        parse_claim(line, FABRIC_SIZE)
+    }
+}
+
+fn parse_claim(line: &str, fabric_size: usize) -> Result<Claim, ParseError> {
+    let mut scanner = Scanner::new(line);
+
+    scanner.expect("#")?;
+    let id = scanner.number()?;
+    scanner.expect("@")?;
+    scanner.skip_whitespace();
+    let start = scanner.column();
+    let x = scanner.number()?;
+    scanner.expect(",")?;
+    let y = scanner.number()?;
+    scanner.expect(":")?;
+    let width = scanner.number()?;
+    scanner.expect("x")?;
+    let height = scanner.number()?;
+    scanner.end()?;
+
+    // every claim must fit on the fabric, or mapping it would go out of bounds
+    let size = fabric_size as i64;
+    let fits = |pos: i64, len: i64| pos >= 0 && len > 0 && len <= size - pos;
+    if !fits(x, width) || !fits(y, height) {
+        let expected = format!("a claim within the {}x{} fabric", size, size);
+        let found = format!("{},{}: {}x{}", x, y, width, height);
+        return Err(ParseError::new(start, &expected, Some(&found)));
+    }
+
+    Ok(Claim {
+        id,
+        area: Rect::from_size(Point2::new(x, y), width, height),
+    })
+}
+
+// Every inch of the fabric holds the number of claims covering it
+fn map_claim(fabric: &mut Grid<u32>, claim: &Claim) {
+    for inch in claim.area.points() {
+        fabric[(inch.x, inch.y)] += 1;
+    }
+}
+
+/// Fabric of `fabric_size` inches with the number of claims covering each inch
+pub fn map_claims(claims: &[Claim], fabric_size: usize) -> Grid<u32> {
+    let mut fabric = Grid::new(fabric_size, fabric_size, 0);
+
+    for claim in claims {
+        map_claim(&mut fabric, claim);
+    }
+
+    fabric
+}
+
+// Who claimed an inch of the fabric, for drawing
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Inch {
+    Free,
+    Claimed(u32),
+    Overlap,
+}
+
+/// Fabric as drawn in the description, from the top left corner to one inch
+/// past the furthest claim
+pub struct Fabric {
+    inches: Grid<Inch>,
+}
+
+impl Fabric {
+    pub fn new(claims: &[Claim]) -> Fabric {
+        let size = |max: i64| max as usize + 1;
+        let width = claims.iter().map(|c| size(c.area.max.x)).max().unwrap_or(0);
+        let height = claims.iter().map(|c| size(c.area.max.y)).max().unwrap_or(0);
+        let mut inches = Grid::new(width, height, Inch::Free);
+
+        for claim in claims {
+            for inch in claim.area.points() {
+                if let Some(cell) = inches.get_mut(inch.x, inch.y) {
+                    *cell = match cell {
+                        Inch::Free => Inch::Claimed(claim.id),
+                        _ => Inch::Overlap,
+                    };
+                }
+            }
+        }
+
+        Fabric { inches }
+    }
+}
+
+// Claimed inches show the last digit of the claim id, X marks overlaps
+impl Render for Fabric {
+    fn render(&self, canvas: &mut Canvas) {
+        for row in self.inches.rows() {
+            for inch in row {
+                match inch {
+                    Inch::Free => canvas.put('.', Color::Gray),
+                    Inch::Claimed(id) => {
+                        let digit = std::char::from_digit(id % 10, 10).unwrap();
+                        canvas.put(digit, Color::cycle(*id as usize));
+                    }
+                    Inch::Overlap => canvas.put('X', Color::Red),
+                }
+            }
+            canvas.newline();
+        }
+    }
+}
+
+/// Day 3 for the runner: the input is the list of claims
+#[derive(Debug, Clone)]
+pub struct Day3 {
+    /// Side of the fabric, `fabric_size` in `aoc.toml`
+    pub fabric_size: usize,
+}
+
+impl Default for Day3 {
+    fn default() -> Day3 {
+        Day3 {
+            fabric_size: FABRIC_SIZE,
+        }
+    }
+}
+
+impl Configure for Day3 {
+    fn configure(params: &mut Params) -> Result<Day3, ConfigError> {
+        let fabric_size = params.take("fabric_size", FABRIC_SIZE)?;
+        // the generator needs room for a claim next to the intact one
+        if !(2..=MAX_FABRIC_SIZE).contains(&fabric_size) {
+            let found = Value::Int(fabric_size as i64);
+            return Err(params.invalid("fabric_size", "from 2 to 5000", found));
+        }
+
+        Ok(Day3 { fabric_size })
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "No Matter How You Slice It";
+
+    type Input = Vec<Claim>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Claim>, Error> {
+        Ok(parse::parse_lines_with(input, |line| {
+            parse_claim(line, self.fabric_size)
+        })?)
+    }
+
+    fn part_one(&self, claims: &Vec<Claim>) -> Result<u32, Error> {
+        Ok(overlapping_inches(claims, self.fabric_size))
+    }
+
+    fn part_two(&self, claims: &Vec<Claim>) -> Result<u32, Error> {
+        find_intact_claim(claims).ok_or_else(|| Error::no_answer("every claim overlaps another"))
+    }
+
+    fn visualize(&self, claims: &Vec<Claim>, canvas: &mut Canvas) {
+        canvas.draw(&Fabric::new(claims));
+    }
+
+    // How many claims cover each inch, the brighter the more
+    fn images(&self, claims: &Vec<Claim>) -> Vec<(&'static str, Image)> {
+        let fabric = map_claims(claims, self.fabric_size);
+        let max = fabric.values().cloned().max().unwrap_or(0);
+
+        vec![(
+            "overlaps",
+            Image::from_grid(&fabric, |claims| image::heat(*claims, max)),
+        )]
+    }
+
+    // About 1300 claims like the real ones, see `random_claims`
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let areas = random_claims(rng, size.unwrap_or(1300), self.fabric_size);
+
+        Some(
+            areas
+                .iter()
+                .enumerate()
+                .map(|(idx, area)| {
+                    format!(
+                        "#{} @ {},{}: {}x{}\n",
+                        idx + 1,
+                        area.min.x,
+                        area.min.y,
+                        area.width(),
+                        area.height()
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+// --- Part Two ---
+// Amidst the chaos, you notice that exactly one claim doesn't overlap
+// by even a single square inch of fabric with any other claim. If you
+// can somehow draw attention to it, maybe the Elves will be able to
+// make Santa's suit after all!
+//
+// For example, in the claims above, only claim 3 is intact after all
+// claims are made.
+//
+// What is the ID of the only claim that doesn't overlap?
+
+// Longest side of a random claim on a fabric of `size` inches
+fn max_side(size: usize) -> i64 {
+    (size as i64 / 2).clamp(1, 30)
+}
+
+// Claim of at most 30x30 inches somewhere on the fabric
+fn random_area(rng: &mut Rng, size: usize) -> Rect<i64> {
+    let (width, height) = (
+        rng.between(1, max_side(size)),
+        rng.between(1, max_side(size)),
+    );
+    let x = rng.between(0, size as i64 - width);
+    let y = rng.between(0, size as i64 - height);
+
+    Rect::from_size(Point2::new(x, y), width, height)
+}
+
+//
+// Areas of `count` claims (at least 3) in random order, exactly one of them
+// overlapping no other claim. The others come in pairs where the second claim
+// starts inside the first, an odd one out starts inside an earlier claim.
+//
+fn random_claims(rng: &mut Rng, count: usize, size: usize) -> Vec<Rect<i64>> {
+    let intact = random_area(rng, size);
+    let others = count.max(3) - 1;
+    let mut areas = vec![];
+
+    while areas.len() < others {
+        let single = areas.len() + 1 == others;
+        let first = if single {
+            *rng.choose(&areas).unwrap()
+        } else {
+            random_area(rng, size)
+        };
+
+        let x = first.min.x + rng.between(0, first.width() - 1);
+        let y = first.min.y + rng.between(0, first.height() - 1);
+        let width = rng.between(1, max_side(size)).min(size as i64 - x);
+        let height = rng.between(1, max_side(size)).min(size as i64 - y);
+        let second = Rect::from_size(Point2::new(x, y), width, height);
+
+        let claims = if single {
+            vec![second]
+        } else {
+            vec![first, second]
+        };
+        if claims
+            .iter()
+            .all(|area| area.intersection(&intact).is_none())
+        {
+            areas.extend(claims);
+        }
+    }
+
+    areas.push(intact);
+    rng.shuffle(&mut areas);
+    areas
+}
+
+/// Inches of fabric within two or more claims
+pub fn overlapping_inches(claims: &[Claim], fabric_size: usize) -> u32 {
+    let fabric = map_claims(claims, fabric_size);
+
+    fabric.values().filter(|claimed| **claimed >= 2).count() as u32
+}
+
+/// Smallest id of a claim not sharing a single inch with other claims
+pub fn find_intact_claim(claims: &[Claim]) -> Option<u32> {
+    claims
+        .iter()
+        .filter(|claim| {
+            claims
+                .iter()
+                .all(|other| other.id == claim.id || claim.area.intersection(&other.area).is_none())
+        })
+        .map(|claim| claim.id)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use utils::property;
+    use utils::Config;
+
+    #[test]
+    fn test_random_claims() {
+        let mut rng = Rng::new(3);
+
+        for count in [1, 4, 7, 50] {
+            let claims: Vec<Claim> = random_claims(&mut rng, count, FABRIC_SIZE)
+                .into_iter()
+                .zip(1..)
+                .map(|(area, id)| Claim { id, area })
+                .collect();
+            let intact = claims
+                .iter()
+                .filter(|claim| {
+                    claims.iter().all(|other| {
+                        other.id == claim.id || claim.area.intersection(&other.area).is_none()
+                    })
+                })
+                .count();
+
+            assert_eq!(claims.len(), count.max(3));
+            assert_eq!(intact, 1);
+        }
+    }
+
+    #[test]
+    fn test_overlap_within_claimed_area() {
+        let claims = |rng: &mut Rng, size| {
+            property::vec_of(rng, size, |rng| {
+                let (x, y) = (rng.below(60) as i64, rng.below(60) as i64);
+                (x, y, rng.between(1, 20), rng.between(1, 20))
+            })
+        };
+
+        property::check("overlap", claims, |claims: &Vec<(i64, i64, i64, i64)>| {
+            let claims: Vec<Claim> = claims
+                .iter()
+                .zip(1..)
+                .map(|(&(x, y, width, height), id)| Claim {
+                    id,
+                    area: Rect::from_size(Point2::new(x, y), width, height),
+                })
+                .collect();
+            let claimed: i64 = claims.iter().map(|claim| claim.area.area()).sum();
+            let overlap = Day3::default().part_one(&claims).unwrap() as i64;
+
+            // every inch of an overlap is covered by at least two claims
+            if 2 * overlap <= claimed {
+                Ok(())
+            } else {
+                Err(format!("{} inches overlap, {} claimed", overlap, claimed))
+            }
+        });
+    }
+
+    #[test]
+    fn test_parse_claims_on_fabric() {
+        assert!(Day3::default().parse("#1 @ 996,0: 4x4\n").is_ok());
+
+        let errors = Day3::default()
+            .parse("#1 @ 997,0: 4x4\n#2 @ 1,3: 0x4\n#3 @ 5,5: 99999999999999999999x2\n")
+            .unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "invalid input: \
+             line 1, column 6: expected a claim within the 1000x1000 fabric, found \"997,0: 4x4\"\n\
+             line 2, column 6: expected a claim within the 1000x1000 fabric, found \"1,3: 0x4\"\n\
+             line 3, column 11: expected a number, found \"99999999999999999999x2\""
+        );
+    }
+
+    #[test]
+    fn test_configure() {
+        let configure = |assignment: &str| {
+            let mut config = Config::new();
+            config.set(assignment).unwrap();
+            config
+                .configure::<Day3>(2018, 3)
+                .map(|day3| day3.fabric_size)
+                .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(configure("2018.3.fabric_size=2"), Ok(2));
+        assert_eq!(configure("2018.3.fabric_size=5000"), Ok(MAX_FABRIC_SIZE));
+        assert_eq!(
+            configure("2018.3.fabric_size=1"),
+            Err(String::from(
+                "[2018.3] fabric_size: expected from 2 to 5000, found 1"
+            ))
+        );
+        assert_eq!(
+            configure("2018.3.fabric_size=10000000"),
+            Err(String::from(
+                "[2018.3] fabric_size: expected from 2 to 5000, found 10000000"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_intact_claim() {
+        let claims = Day3::default()
+            .parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n")
+            .unwrap();
+        assert_eq!(
+            Day3::default().part_two(&claims).unwrap_err().to_string(),
+            "no answer: every claim overlaps another"
+        );
+
+        let claims = Day3::default()
+            .parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n")
+            .unwrap();
+        assert_eq!(Day3::default().part_two(&claims).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_render_fabric() {
+        let claims = Day3::default()
+            .parse("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n")
+            .unwrap();
+
+        assert_eq!(
+            Fabric::new(&claims).to_plain(),
+            indoc!(
+                "
+                ........
+                ...2222.
+                ...2222.
+                .11XX22.
+                .11XX22.
+                .111133.
+                .111133.
+                ........
+                "
+            )
+        );
+    }
+}
@@ -0,0 +1,696 @@
+// --- Day 4: Repose Record ---
+// You've sneaked into another supply closet - this time, it's across from the
+// prototype suit manufacturing lab. You need to sneak inside and fix the issues
+// with the suit, but there's a guard stationed outside the lab, so this is as
+// close as you can safely get.
+//
+// As you search the closet for anything that might help, you discover that
+// you're not the first person to want to sneak in. Covering the walls, someone
+// has spent an hour starting every midnight for the past few months secretly
+// observing this guard post! They've been writing down the ID of the one guard
+// on duty that night - the Elves seem to have decided that one guard was enough
+// for the overnight shift - as well as when they fall asleep or wake up while
+// at their post (your puzzle input).
+//
+// For example, consider the following records, which have already been
+// organized into chronological order:
+//
+// [1518-11-01 00:00] Guard #10 begins shift
+// [1518-11-01 00:05] falls asleep
+// [1518-11-01 00:25] wakes up
+// [1518-11-01 00:30] falls asleep
+// [1518-11-01 00:55] wakes up
+// [1518-11-01 23:58] Guard #99 begins shift
+// [1518-11-02 00:40] falls asleep
+// [1518-11-02 00:50] wakes up
+// [1518-11-03 00:05] Guard #10 begins shift
+// [1518-11-03 00:24] falls asleep
+// [1518-11-03 00:29] wakes up
+// [1518-11-04 00:02] Guard #99 begins shift
+// [1518-11-04 00:36] falls asleep
+// [1518-11-04 00:46] wakes up
+// [1518-11-05 00:03] Guard #99 begins shift
+// [1518-11-05 00:45] falls asleep
+// [1518-11-05 00:55] wakes up
+//
+// Timestamps are written using year-month-day hour:minute format. The guard
+// falling asleep or waking up is always the one whose shift most recently
+// started. Because all asleep/awake times are during the midnight hour (00:00 -
+// 00:59), only the minute portion (00 - 59) is relevant for those events.
+//
+// Visually, these records show that the guards are asleep at these times:
+//
+// Date   ID   Minute
+//             000000000011111111112222222222333333333344444444445555555555
+//             012345678901234567890123456789012345678901234567890123456789
+// 11-01  #10  .....####################.....#########################.....
+// 11-02  #99  ........................................##########..........
+// 11-03  #10  ........................#####...............................
+// 11-04  #99  ....................................##########..............
+// 11-05  #99  .............................................##########.....
+//
+// The columns are Date, which shows the month-day portion of the relevant day;
+// ID, which shows the guard on duty that day; and Minute, which shows the
+// minutes during which the guard was asleep within the midnight hour. (The
+// Minute column's header shows the minute's ten's digit in the first row and
+// the one's digit in the second row.) Awake is shown as ., and asleep is shown
+// as #.
+//
+// Note that guards count as asleep on the minute they fall asleep, and they
+// count as awake on the minute they wake up. For example, because Guard #10
+// wakes up at 00:25 on 1518-11-01, minute 25 is marked as awake.
+//
+// If you can figure out the guard most likely to be asleep at a specific time,
+// you might be able to trick that guard into working tonight so you can have
+// the best chance of sneaking in. You have two strategies for choosing the best
+// guard/minute combination.
+//
+// Strategy 1: Find the guard that has the most minutes asleep. What minute does
+// that guard spend asleep the most?
+//
+// In the example above, Guard #10 spent the most minutes asleep, a total of 50
+// minutes (20+25+5), while Guard #99 only slept for a total of 30 minutes
+// (10+10+10). Guard #10 was asleep most during minute 24 (on two days, whereas
+// any other minute the guard was asleep was only seen on one day).
+//
+// While this example listed the entries in chronological order, your entries
+// are in the order you found them. You'll need to organize them before they can
+// be analyzed.
+//
+// What is the ID of the guard you chose multiplied by the minute you chose? (In
+// the above example, the answer would be 10 * 24 = 240.)
+
+use chrono::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use utils::parse::Scanner;
+use utils::{
+    parse_lines, Answer, Canvas, Color, ConfigError, Configure, Counter, Error, Params, ParseError,
+    Render, Rng, Solution,
+};
+
+/// Minutes of the midnight hour a guard slept through during one shift
+#[derive(Debug, Clone, Default)]
+pub struct ShiftTimeline {
+    data: Vec<u32>,
+}
+
+impl ShiftTimeline {
+    pub fn new() -> ShiftTimeline {
+        ShiftTimeline { data: Vec::new() }
+    }
+
+    /// Every minute asleep, in the order the guard slept
+    pub fn minutes(&self) -> &[u32] {
+        &self.data
+    }
+
+    pub fn sleep_minutes(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    fn record_sleep_time(&mut self, asleep_at: Option<NaiveDateTime>, awake_at: NaiveDateTime) {
+        // `check_records` makes sure every guard waking up fell asleep before
+        if let Some(asleep_at_time) = asleep_at {
+            let start_min = asleep_at_time.minute();
+            let end_min = awake_at.minute();
+            for idx in start_min..end_min {
+                self.data.push(idx);
+            }
+        }
+    }
+}
+
+/// What a record says happened
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionType {
+    Shift,
+    Asleep,
+    Awake,
+}
+
+/// One line of the records
+#[derive(Debug)]
+pub struct Action {
+    kind: ActionType,
+    time: NaiveDateTime,
+    guard_id: Option<u32>,
+}
+
+impl Action {
+    pub fn kind(&self) -> ActionType {
+        self.kind
+    }
+
+    pub fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+
+    /// Only shifts name the guard, the others are about the guard on duty
+    pub fn guard_id(&self) -> Option<u32> {
+        self.guard_id
+    }
+}
+
+// [1518-11-01 00:00] Guard #10 begins shift
+impl FromStr for Action {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Action, ParseError> {
+        let mut scanner = Scanner::new(line);
+
+        scanner.expect("[")?;
+        let column = scanner.column();
+        let stamp = scanner.take_until("]")?;
+        let time = NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M")
+            .map_err(|_| ParseError::new(column, "a timestamp", Some(stamp)))?;
+        scanner.expect("]")?;
+
+        let (kind, guard_id) = if scanner.eat("Guard") {
+            scanner.expect("#")?;
+            let guard_id = scanner.number()?;
+            scanner.expect("begins shift")?;
+            (ActionType::Shift, Some(guard_id))
+        } else if scanner.eat("falls asleep") {
+            (ActionType::Asleep, None)
+        } else if scanner.eat("wakes up") {
+            (ActionType::Awake, None)
+        } else {
+            return Err(scanner.error("\"Guard\", \"falls asleep\" or \"wakes up\""));
+        };
+        scanner.end()?;
+
+        Ok(Action {
+            kind,
+            time,
+            guard_id,
+        })
+    }
+}
+
+//
+// Records in order must tell a story the strategies can follow: a shift comes
+// first, guards fall asleep and wake up in turn within the midnight hour of
+// the same night and someone sleeps at all. `lines` are the input lines of
+// the actions, for pointing at the record that breaks the story.
+//
+fn check_records(actions: &[Action], lines: &[usize]) -> Result<(), ParseError> {
+    let mut on_duty = false;
+    let mut asleep_at: Option<NaiveDateTime> = None;
+    let mut napped = false;
+
+    for (action, &line) in actions.iter().zip(lines) {
+        let stamp = action.time.format("%Y-%m-%d %H:%M").to_string();
+        let error = |expected: &str, found: &str| {
+            Err(ParseError::new(2, expected, Some(found)).at_line(line))
+        };
+
+        match action.kind {
+            ActionType::Shift if asleep_at.is_some() => {
+                return error("\"wakes up\"", "Guard");
+            }
+            ActionType::Shift => on_duty = true,
+            _ if !on_duty => return error("a guard beginning the shift", &stamp),
+            ActionType::Asleep if asleep_at.is_some() => {
+                return error("\"wakes up\"", "falls asleep");
+            }
+            ActionType::Awake if asleep_at.is_none() => {
+                return error("\"falls asleep\"", "wakes up");
+            }
+            _ if action.time.hour() != 0 => {
+                return error("a time within the midnight hour", &stamp);
+            }
+            ActionType::Asleep => asleep_at = Some(action.time),
+            ActionType::Awake => {
+                let asleep = asleep_at.take().unwrap_or(action.time);
+                if asleep.date() != action.time.date() || asleep >= action.time {
+                    return error("a time after falling asleep that night", &stamp);
+                }
+                napped = true;
+            }
+        }
+    }
+
+    if asleep_at.is_some() {
+        return Err(ParseError::input(
+            "\"wakes up\"",
+            Some("a guard still asleep"),
+        ));
+    }
+    if !napped {
+        return Err(ParseError::input("a guard falling asleep", None));
+    }
+    Ok(())
+}
+
+/// Shifts of every guard, records before the first shift are filed under None
+pub fn get_records(actions: &[Action]) -> HashMap<Option<u32>, Vec<ShiftTimeline>> {
+    // Not every action has guard_id initially and since they are sorted by time
+    // now and first action in a row for given guard is usually "Shift" and contains
+    // guard id. So we can fill guard_id from initial action...
+    let mut shift_timeline = ShiftTimeline::new();
+    let mut guard_id: Option<u32> = None;
+    let mut asleep_at: Option<NaiveDateTime> = None;
+    let mut records: HashMap<Option<u32>, Vec<ShiftTimeline>> = HashMap::new();
+
+    for action in actions {
+        match action.kind {
+            ActionType::Shift => {
+                // first lets save previous ShiftTimeline
+                records.entry(guard_id).or_default().push(shift_timeline);
+                guard_id = action.guard_id;
+                shift_timeline = ShiftTimeline::new();
+            }
+            ActionType::Asleep => asleep_at = Some(action.time),
+            ActionType::Awake => {
+                let awake_at = action.time;
+                shift_timeline.record_sleep_time(asleep_at, awake_at);
+            }
+        }
+    }
+
+    // ...and the shift of the last guard, which is not followed by another "Shift"
+    records.entry(guard_id).or_default().push(shift_timeline);
+
+    records
+}
+
+// Total minutes asleep per guard
+fn get_sleep_minutes_per_guard_id(
+    records: &HashMap<Option<u32>, Vec<ShiftTimeline>>,
+) -> Counter<u32> {
+    let mut counters = Counter::new();
+
+    for (guard_id, shift_timelines) in records {
+        if let Some(guard_id) = guard_id {
+            let sleep_minutes: u32 = shift_timelines.iter().map(|t| t.sleep_minutes()).sum();
+            counters.add_n(*guard_id, sleep_minutes as usize);
+        }
+    }
+
+    counters
+}
+
+// One night at the guard post, a row of the chart in the description
+struct Night {
+    date: NaiveDate,
+    guard: u32,
+    asleep: [bool; 60],
+}
+
+/// Minutes each guard spent asleep, night by night
+pub struct Chart {
+    nights: Vec<Night>,
+}
+
+impl Chart {
+    /// Chart of records sorted by time, as `Day4::parse` returns them
+    pub fn new(actions: &[Action]) -> Chart {
+        let mut nights: Vec<Night> = vec![];
+        let mut asleep_at = 0;
+
+        for action in actions {
+            match action.kind {
+                ActionType::Shift => {
+                    // shifts starting before midnight are charted on the next day
+                    let mut date = action.time.date();
+                    if action.time.hour() != 0 {
+                        date = date.succ_opt().unwrap_or(date);
+                    }
+
+                    nights.push(Night {
+                        date,
+                        guard: action.guard_id.unwrap_or_default(),
+                        asleep: [false; 60],
+                    });
+                }
+                ActionType::Asleep => asleep_at = action.time.minute(),
+                ActionType::Awake => {
+                    if let Some(night) = nights.last_mut() {
+                        for minute in asleep_at..action.time.minute() {
+                            night.asleep[minute as usize] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        Chart { nights }
+    }
+}
+
+impl Render for Chart {
+    fn render(&self, canvas: &mut Canvas) {
+        let ids: Vec<String> = self
+            .nights
+            .iter()
+            .map(|n| format!("#{}", n.guard))
+            .collect();
+        let id_width = ids.iter().map(|id| id.len()).max().unwrap_or(0).max(2);
+        let indent = " ".repeat(7 + id_width + 2);
+
+        canvas.line(&format!(
+            "{:<7}{:<w$}Minute",
+            "Date",
+            "ID",
+            w = id_width + 2
+        ));
+        canvas.text(&indent);
+        canvas.colored(
+            &(0..60)
+                .map(|m| m / 10)
+                .map(|d| d.to_string())
+                .collect::<String>(),
+            Color::Gray,
+        );
+        canvas.newline();
+        canvas.text(&indent);
+        canvas.colored(
+            &(0..60)
+                .map(|m| m % 10)
+                .map(|d| d.to_string())
+                .collect::<String>(),
+            Color::Gray,
+        );
+        canvas.newline();
+
+        for (night, id) in self.nights.iter().zip(ids) {
+            canvas.text(&format!(
+                "{}  {:<w$}  ",
+                night.date.format("%m-%d"),
+                id,
+                w = id_width
+            ));
+            for asleep in night.asleep.iter() {
+                match asleep {
+                    true => canvas.put('#', Color::Yellow),
+                    false => canvas.put('.', Color::Gray),
+                }
+            }
+            canvas.newline();
+        }
+    }
+}
+
+/// Guard picked by a strategy, the answer is id multiplied by the minute
+#[derive(Debug, PartialEq)]
+pub struct Sleephead {
+    pub guard: u32,
+    pub minute: u32,
+}
+
+impl fmt::Display for Sleephead {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.guard * self.minute)
+    }
+}
+
+impl Answer for Sleephead {
+    fn diagnostics(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("guard", self.guard.to_string()),
+            ("minute", self.minute.to_string()),
+        ]
+    }
+}
+
+/// Parses records and sorts them by time, checking they make sense in order
+pub struct Day4;
+
+// Shifts always watch the midnight hour, there is nothing to configure
+impl Configure for Day4 {
+    fn configure(_params: &mut Params) -> Result<Day4, ConfigError> {
+        Ok(Day4)
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Repose Record";
+
+    type Input = Vec<Action>;
+    type Answer1 = Sleephead;
+    type Answer2 = Sleephead;
+
+    fn parse(&self, input: &str) -> Result<Vec<Action>, Error> {
+        let actions: Vec<Action> = parse_lines(input)?;
+        let lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, _)| idx + 1);
+
+        // stable, records of the same minute stay in input order
+        let mut sorted: Vec<(Action, usize)> = actions.into_iter().zip(lines).collect();
+        sorted.sort_by_key(|(action, _)| action.time);
+        let (actions, lines): (Vec<Action>, Vec<usize>) = sorted.into_iter().unzip();
+        check_records(&actions, &lines)?;
+
+        for action in &actions {
+            utils::trace!("day4", "action parsed";
+                time = action.time, kind = format!("{:?}", action.kind),
+                guard = action.guard_id.map_or(String::from("-"), |id| id.to_string()));
+        }
+        utils::info!("day4", "records parsed"; actions = actions.len());
+
+        Ok(actions)
+    }
+
+    fn part_one(&self, actions: &Vec<Action>) -> Result<Sleephead, Error> {
+        sleepiest_guard(actions)
+    }
+
+    fn part_two(&self, actions: &Vec<Action>) -> Result<Sleephead, Error> {
+        most_frequent_sleeper(actions)
+    }
+
+    fn visualize(&self, actions: &Vec<Action>, canvas: &mut Canvas) {
+        canvas.draw(&Chart::new(actions));
+    }
+
+    // About 250 nights, see `random_records`
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut records = random_records(rng, size.unwrap_or(250));
+        rng.shuffle(&mut records);
+
+        Some(records.into_iter().map(|record| record + "\n").collect())
+    }
+}
+
+//
+// Records of `nights` nights in order, starting on 1518-01-01. A guard out of
+// one for every ten nights begins the shift shortly before or after midnight
+// and naps up to three times within the midnight hour. Someone naps on the
+// first night, so there is always a guard to pick.
+//
+fn random_records(rng: &mut Rng, nights: usize) -> Vec<String> {
+    let guards: Vec<u32> = (0..nights.max(10) / 10)
+        .map(|_| rng.between(10, 3500) as u32)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let mut date = NaiveDate::from_ymd_opt(1518, 1, 1).unwrap();
+    let mut records = vec![];
+
+    for night in 0..nights.max(1) {
+        let guard = rng.choose(&guards).unwrap();
+        let shift = if rng.chance(0.5) {
+            date.pred_opt()
+                .unwrap()
+                .and_hms_opt(23, rng.between(45, 59) as u32, 0)
+        } else {
+            date.and_hms_opt(0, rng.between(0, 3) as u32, 0)
+        };
+        records.push(format!(
+            "[{}] Guard #{} begins shift",
+            shift.unwrap().format("%Y-%m-%d %H:%M"),
+            guard
+        ));
+
+        // falling asleep and waking up alternate on distinct minutes
+        let naps = rng.between(i64::from(night == 0), 3) as usize;
+        let mut minutes = BTreeSet::new();
+        while minutes.len() < 2 * naps {
+            minutes.insert(rng.between(5, 59));
+        }
+        for (idx, minute) in minutes.into_iter().enumerate() {
+            let what = if idx % 2 == 0 {
+                "falls asleep"
+            } else {
+                "wakes up"
+            };
+            records.push(format!(
+                "[{} 00:{:02}] {}",
+                date.format("%Y-%m-%d"),
+                minute,
+                what
+            ));
+        }
+
+        date = date.succ_opt().unwrap();
+    }
+
+    records
+}
+
+/// Strategy 1: the guard asleep for the most minutes and the minute that guard
+/// slept through most often. Ties go to the lowest guard id and the earliest
+/// minute.
+pub fn sleepiest_guard(actions: &[Action]) -> Result<Sleephead, Error> {
+    let records = get_records(actions);
+    let sleep_counts = get_sleep_minutes_per_guard_id(&records);
+    let no_nap = || Error::no_answer("no guard falls asleep");
+
+    let sleephead = **sleep_counts.argmax().first().ok_or_else(no_nap)?;
+
+    let minute_counts: Counter<u32> = records
+        .get(&Some(sleephead))
+        .into_iter()
+        .flatten()
+        .flat_map(|h| h.data.iter().cloned())
+        .collect();
+
+    let sleep_mostly_on_minute = **minute_counts.argmax().first().ok_or_else(no_nap)?;
+
+    Ok(Sleephead {
+        guard: sleephead,
+        minute: sleep_mostly_on_minute,
+    })
+}
+
+// --- Part Two ---
+// Strategy 2: Of all guards, which guard is most frequently asleep on the same minute?
+//
+// In the example above, Guard #99 spent minute 45 asleep more than any other
+// guard or minute - three times in total. (In all other cases, any guard spent
+// any minute asleep at most twice.)
+//
+// What is the ID of the guard you chose multiplied by the minute you chose? (In
+// the above example, the answer would be 99 * 45 = 4455.)
+
+/// Strategy 2: the guard most frequently asleep on the same minute, and that
+/// minute
+pub fn most_frequent_sleeper(actions: &[Action]) -> Result<Sleephead, Error> {
+    let records = get_records(actions);
+
+    // Option<GuardId> => minute counts, ordered so ties go to the lowest id
+    let mut minute_counters_by_guard: BTreeMap<Option<u32>, Counter<u32>> = BTreeMap::new();
+
+    // Calculate times each guard spent sleeping at each minute
+    for (guard_id, shift_timelines) in records {
+        let counters = minute_counters_by_guard.entry(guard_id).or_default();
+        for timeline in shift_timelines {
+            counters.extend(timeline.data);
+        }
+    }
+
+    // Find minute most popular for sleep in scope of single guard id
+    let mut sleephead_id: Option<u32> = None;
+    let mut sleepy_minute = 0;
+    let mut sleepy_minute_used_times = 0;
+
+    for (guard_id, counters) in minute_counters_by_guard {
+        let times = counters.max_count();
+
+        if times > sleepy_minute_used_times {
+            sleepy_minute = *counters.argmax()[0];
+            sleephead_id = guard_id;
+            sleepy_minute_used_times = times;
+        }
+    }
+
+    match sleephead_id {
+        Some(guard) => Ok(Sleephead {
+            guard,
+            minute: sleepy_minute,
+        }),
+        None => Err(Error::no_answer("no guard falls asleep")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_checks_records() {
+        let error = |input: &str| Day4.parse(input).err().map(|errors| errors.to_string());
+
+        assert_eq!(
+            error("[1518-11-01 00:05] falls asleep\n[1518-11-01 00:25] wakes up\n"),
+            Some(String::from(
+                "invalid input: line 1, column 2: expected a guard beginning the shift, found \"1518-11-01 00:05\""
+            ))
+        );
+        // records are sorted by time first, lines still point into the input
+        assert_eq!(
+            error(indoc!(
+                "
+                [1518-11-01 00:25] wakes up
+                [1518-11-01 00:00] Guard #10 begins shift
+                [1518-11-01 00:30] falls asleep
+                "
+            )),
+            Some(String::from(
+                "invalid input: line 1, column 2: expected \"falls asleep\", found \"wakes up\""
+            ))
+        );
+        assert_eq!(
+            error("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep\n"),
+            Some(String::from(
+                "invalid input: input: expected \"wakes up\", found \"a guard still asleep\""
+            ))
+        );
+        assert_eq!(
+            error("[1518-11-01 00:00] Guard #10 begins shift\n"),
+            Some(String::from(
+                "invalid input: input: expected a guard falling asleep, found end of input"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_render_chart() {
+        let actions = Day4
+            .parse(indoc!(
+                "
+            [1518-11-01 00:00] Guard #10 begins shift
+            [1518-11-01 00:05] falls asleep
+            [1518-11-01 00:25] wakes up
+            [1518-11-01 00:30] falls asleep
+            [1518-11-01 00:55] wakes up
+            [1518-11-01 23:58] Guard #99 begins shift
+            [1518-11-02 00:40] falls asleep
+            [1518-11-02 00:50] wakes up
+            [1518-11-03 00:05] Guard #10 begins shift
+            [1518-11-03 00:24] falls asleep
+            [1518-11-03 00:29] wakes up
+            [1518-11-04 00:02] Guard #99 begins shift
+            [1518-11-04 00:36] falls asleep
+            [1518-11-04 00:46] wakes up
+            [1518-11-05 00:03] Guard #99 begins shift
+            [1518-11-05 00:45] falls asleep
+            [1518-11-05 00:55] wakes up
+            "
+            ))
+            .unwrap();
+
+        assert_eq!(
+            Chart::new(&actions).to_plain(),
+            indoc!(
+                "
+                Date   ID   Minute
+                            000000000011111111112222222222333333333344444444445555555555
+                            012345678901234567890123456789012345678901234567890123456789
+                11-01  #10  .....####################.....#########################.....
+                11-02  #99  ........................................##########..........
+                11-03  #10  ........................#####...............................
+                11-04  #99  ....................................##########..............
+                11-05  #99  .............................................##########.....
+                "
+            )
+        );
+    }
+}
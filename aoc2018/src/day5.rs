@@ -0,0 +1,316 @@
+// --- Day 5: Alchemical Reduction ---
+// You've managed to sneak in to the prototype suit manufacturing lab. The Elves
+// are making decent progress, but are still struggling with the suit's size
+// reduction capabilities.
+//
+// While the very latest in 1518 alchemical technology might have solved their
+// problem eventually, you can do better. You scan the chemical composition of
+// the suit's material and discover that it is formed by extremely long polymers
+// (one of which is available as your puzzle input).
+//
+// The polymer is formed by smaller units which, when triggered, react with each
+// other such that two adjacent units of the same type and opposite polarity are
+// destroyed. Units' types are represented by letters; units' polarity is
+// represented by capitalization. For instance, r and R are units with the same
+// type but opposite polarity, whereas r and s are entirely different types and
+// do not react.
+//
+// For example:
+//
+// In aA, a and A react, leaving nothing behind.
+// In abBA, bB destroys itself, leaving aA. As above, this then destroys itself, leaving nothing.
+// In abAB, no two adjacent units are of the same type, and so nothing happens.
+// In aabAAB, even though aa and AA are of the same type, their polarities match, and so nothing happens.
+// Now, consider a larger example, dabAcCaCBAcCcaDA:
+//
+// dabAcCaCBAcCcaDA  The first 'cC' is removed.
+// dabAaCBAcCcaDA    This creates 'Aa', which is removed.
+// dabCBAcCcaDA      Either 'cC' or 'Cc' are removed (the result is the same).
+// dabCBAcaDA        No further actions can be taken.
+//
+// After all possible reactions, the resulting polymer contains 10 units.
+//
+// How many units remain after fully reacting the polymer you scanned? (Note: in
+// this puzzle and others, the input is large; if you copy/paste your input,
+// make sure you get the whole thing.)
+
+use std::fmt;
+use utils::config::Value;
+use utils::{Answer, ConfigError, Configure, Counter, Error, Params, ParseError, Rng, Solution};
+
+/// Unit types of the puzzle, by their lowercase letter
+pub const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn should_be_destroyed(prev: char, next: char) -> bool {
+    let are_polar =
+        prev.is_uppercase() && next.is_lowercase() || prev.is_lowercase() && next.is_uppercase();
+
+    if are_polar {
+        prev.eq_ignore_ascii_case(&next)
+    } else {
+        false
+    }
+}
+
+/// Fully reacts `polymer`, returns what is left of it and how many units of
+/// each type were destroyed (counted by the uppercase letter)
+pub fn cleanup_polymer(polymer: &str) -> (String, Counter<char>) {
+    let mut result = Vec::new();
+    let mut counters = Counter::new();
+
+    for unit in polymer.chars() {
+        match result.last() {
+            Some(&last_unit) if should_be_destroyed(last_unit, unit) => {
+                result.pop();
+                utils::trace!("day5", "units collapsed";
+                    position = result.len(), units = format!("{}{}", last_unit, unit));
+                counters.add(unit.to_ascii_uppercase());
+            }
+            _ => result.push(unit),
+        }
+    }
+
+    let final_polymer: String = result.iter().collect();
+
+    (final_polymer, counters)
+}
+
+/// Length of the fully reacted polymer after removing one unit type
+#[derive(Debug, PartialEq)]
+pub struct ShortestPolymer {
+    pub length: usize,
+    pub removed: Option<char>,
+}
+
+impl fmt::Display for ShortestPolymer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.length)
+    }
+}
+
+impl Answer for ShortestPolymer {
+    fn diagnostics(&self) -> Vec<(&'static str, String)> {
+        match self.removed {
+            Some(unit) => vec![("removed", unit.to_string())],
+            None => vec![],
+        }
+    }
+}
+
+/// Parses the single line of the polymer
+#[derive(Debug, Clone)]
+pub struct Day5 {
+    /// Lowercase letters of the unit types, `alphabet` in `aoc.toml`
+    pub alphabet: String,
+}
+
+impl Default for Day5 {
+    fn default() -> Day5 {
+        Day5 {
+            alphabet: String::from(ALPHABET),
+        }
+    }
+}
+
+impl Configure for Day5 {
+    fn configure(params: &mut Params) -> Result<Day5, ConfigError> {
+        let alphabet: String = params.take("alphabet", String::from(ALPHABET))?;
+
+        let letters: Counter<char> = alphabet.chars().collect();
+        let valid =
+            alphabet.chars().all(|letter| letter.is_ascii_lowercase()) && letters.max_count() == 1;
+        if alphabet.is_empty() || !valid {
+            return Err(params.invalid(
+                "alphabet",
+                "distinct lowercase letters",
+                Value::Str(alphabet),
+            ));
+        }
+
+        Ok(Day5 { alphabet })
+    }
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Alchemical Reduction";
+
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = ShortestPolymer;
+
+    // A single line of unit letters
+    fn parse(&self, input: &str) -> Result<String, Error> {
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (number, line) = lines.next().unwrap_or((0, ""));
+
+        if let Some((idx, line)) = lines.next() {
+            let found = line.split_whitespace().next();
+            return Err(ParseError::new(1, "end of input", found)
+                .at_line(idx + 1)
+                .into());
+        }
+
+        let polymer = line.trim();
+        let indent = line.len() - line.trim_start().len();
+        if let Some((column, unit)) = polymer
+            .chars()
+            .enumerate()
+            .find(|(_, unit)| !self.is_unit(*unit))
+        {
+            let found = unit.to_string();
+            return Err(ParseError::new(indent + column + 1, "a unit", Some(&found))
+                .at_line(number + 1)
+                .into());
+        }
+
+        Ok(polymer.to_string())
+    }
+
+    fn part_one(&self, polymer: &String) -> Result<usize, Error> {
+        let (final_polymer, destroyed) = cleanup_polymer(polymer);
+
+        utils::info!("day5", "polymer reacted";
+            length = final_polymer.len(), destroyed = destroyed.total());
+        Ok(final_polymer.len())
+    }
+
+    fn part_two(&self, polymer: &String) -> Result<ShortestPolymer, Error> {
+        Ok(find_bad_unit(polymer, &self.alphabet))
+    }
+
+    // About 50000 units of random types of the alphabet and polarities. Every third unit or so
+    // is the opposite of the one before it, so there is plenty to react.
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let alphabet: Vec<char> = self.alphabet.chars().collect();
+        let mut polymer = String::new();
+        let mut last = None;
+
+        for _ in 0..size.unwrap_or(50_000).max(1) {
+            let unit = match last {
+                Some(last) if rng.chance(0.3) => opposite(last),
+                _ if rng.chance(0.5) => rng.choose(&alphabet)?.to_ascii_uppercase(),
+                _ => *rng.choose(&alphabet)?,
+            };
+            polymer.push(unit);
+            last = Some(unit);
+        }
+
+        Some(polymer + "\n")
+    }
+}
+
+impl Day5 {
+    // Letter of either polarity of a type of the alphabet
+    fn is_unit(&self, unit: char) -> bool {
+        unit.is_ascii_alphabetic() && self.alphabet.contains(unit.to_ascii_lowercase())
+    }
+}
+
+// Same type, other polarity
+fn opposite(unit: char) -> char {
+    if unit.is_ascii_uppercase() {
+        unit.to_ascii_lowercase()
+    } else {
+        unit.to_ascii_uppercase()
+    }
+}
+
+// --- Part Two ---
+// Time to improve the polymer.
+
+// One of the unit types is causing problems; it's preventing the polymer from
+// collapsing as much as it should. Your goal is to figure out which unit type
+// is causing the most problems, remove all instances of it (regardless of
+// polarity), fully react the remaining polymer, and measure its length.
+
+// For example, again using the polymer dabAcCaCBAcCcaDA from above:
+
+// Removing all A/a units produces dbcCCBcCcD. Fully reacting this polymer produces dbCBcD, which has length 6.
+// Removing all B/b units produces daAcCaCAcCcaDA. Fully reacting this polymer produces daCAcaDA, which has length 8.
+// Removing all C/c units produces dabAaBAaDA. Fully reacting this polymer produces daDA, which has length 4.
+// Removing all D/d units produces abAcCaCBAcCcaA. Fully reacting this polymer produces abCBAc, which has length 6.
+
+// In this example, removing all C/c units was best, producing the answer 4.
+
+// What is the length of the shortest polymer you can produce by removing all
+// units of exactly one type and fully reacting the result?
+
+/// Shortest polymer left after removing all units of one type, and the type
+/// removed (lowercase). The unit types of the polymer are tried in the order
+/// of `alphabet` and the first one wins a tie, None if no removal makes the
+/// polymer shorter.
+pub fn find_bad_unit(polymer: &str, alphabet: &str) -> ShortestPolymer {
+    let units: Counter<char> = polymer.chars().map(|el| el.to_ascii_lowercase()).collect();
+
+    let mut min_length = polymer.len();
+    let mut bad_unit = None;
+
+    for letter_to_remove in alphabet.chars().filter(|letter| units.get(letter) > 0) {
+        let candidate: String = polymer
+            .chars()
+            .filter(|el| el.to_ascii_lowercase() != letter_to_remove)
+            .collect();
+
+        let (candidate_reacted, _) = cleanup_polymer(&candidate);
+        utils::debug!("day5", "unit removed";
+            unit = letter_to_remove, length = candidate_reacted.len());
+
+        if candidate_reacted.len() < min_length {
+            min_length = candidate_reacted.len();
+            bad_unit = Some(letter_to_remove);
+        }
+    }
+
+    ShortestPolymer {
+        length: min_length,
+        removed: bad_unit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::property;
+
+    // Few unit types, so plenty of them react
+    fn polymer(rng: &mut Rng, size: usize) -> String {
+        property::string_of(rng, size, "aAbBcC")
+    }
+
+    #[test]
+    fn test_reacted_polymer_has_no_reactive_pairs() {
+        property::check("no reactive pairs", polymer, |polymer: &String| {
+            let (reacted, destroyed) = cleanup_polymer(polymer);
+            let units: Vec<char> = reacted.chars().collect();
+
+            if let Some(pair) = units
+                .windows(2)
+                .find(|pair| should_be_destroyed(pair[0], pair[1]))
+            {
+                return Err(format!("{:?} left in {:?}", pair, reacted));
+            }
+            if reacted.len() + 2 * destroyed.total() != polymer.len() {
+                return Err(format!(
+                    "{} destroyed to get {:?}",
+                    destroyed.total(),
+                    reacted
+                ));
+            }
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_reacting_is_idempotent() {
+        property::check("reacting twice", polymer, |polymer: &String| {
+            let (reacted, _) = cleanup_polymer(polymer);
+            let (again, destroyed) = cleanup_polymer(&reacted);
+
+            again == reacted && destroyed.is_empty()
+        });
+    }
+}
@@ -0,0 +1,936 @@
+// --- Day 6: Chronal Coordinates ---
+// The device on your wrist beeps several times, and once again you feel like
+// you're falling.
+//
+// "Situation critical," the device announces. "Destination indeterminate.
+// Chronal interference detected. Please specify new target coordinates."
+//
+// The device then produces a list of coordinates (your puzzle input). Are they
+// places it thinks are safe or dangerous? It recommends you check manual page
+// 729. The Elves did not give you a manual.
+//
+// If they're dangerous, maybe you can minimize the danger by finding the
+// coordinate that gives the largest distance from the other points.
+//
+// Using only the Manhattan distance, determine the area around each coordinate
+// by counting the number of integer X,Y locations that are closest to that
+// coordinate (and aren't tied in distance to any other coordinate).
+//
+// Your goal is to find the size of the largest area that isn't infinite. For
+// example, consider the following list of coordinates:
+//
+// 1, 1
+// 1, 6
+// 8, 3
+// 3, 4
+// 5, 5
+// 8, 9
+//
+// If we name these coordinates A through F, we can draw them on a grid, putting 0,0 at the top left:
+//
+// ..........
+// .A........
+// ..........
+// ........C.
+// ...D......
+// .....E....
+// .B........
+// ..........
+// ..........
+// ........F.
+//
+// This view is partial - the actual grid extends infinitely in all directions.
+// Using the Manhattan distance, each location's closest coordinate can be
+// determined, shown here in lowercase:
+//
+// aaaaa.cccc
+// aAaaa.cccc
+// aaaddecccc
+// aadddeccCc
+// ..dDdeeccc
+// bb.deEeecc
+// bBb.eeee..
+// bbb.eeefff
+// bbb.eeffff
+// bbb.ffffFf
+//
+// Locations shown as . are equally far from two or more coordinates, and so
+// they don't count as being closest to any.
+//
+// In this example, the areas of coordinates A, B, C, and F are infinite - while
+// not shown here, their areas extend forever outside the visible grid. However,
+// the areas of coordinates D and E are finite: D is closest to 9 locations, and
+// E is closest to 17 (both including the coordinate's location itself).
+// Therefore, in this example, the size of the largest area is 17.
+//
+// What is the size of the largest area that isn't infinite?
+//
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use utils::image::{self, Image, Rgb};
+use utils::parse::Scanner;
+use utils::{
+    parse_lines, Answer, Canvas, Color, ConfigError, Configure, Error, Grid, Params, ParseError,
+    Point2, Rect, Render, Rng, Solution,
+};
+
+/// Named coordinate, two points at the same position are equal
+#[derive(Debug, Eq, Clone)]
+pub struct Point {
+    id: String, // use &str ?
+    position: Point2<i32>,
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Point) -> bool {
+        self.position == other.position
+    }
+}
+
+impl Point {
+    pub fn new(id: &str, x: i32, y: i32) -> Point {
+        Point {
+            id: String::from(id),
+            position: Point2::new(x, y),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn position(&self) -> Point2<i32> {
+        self.position
+    }
+
+    fn with_id(self, id: &str) -> Point {
+        Point {
+            id: String::from(id),
+            ..self
+        }
+    }
+
+    pub fn rectilinear_distance(&self, other: &Point) -> u32 {
+        self.position.manhattan(&other.position) as u32
+    }
+
+    pub fn sum_distances(&self, points: &[Point]) -> u32 {
+        points
+            .iter()
+            .map(|point| self.rectilinear_distance(point))
+            .sum()
+    }
+
+    //
+    // Return closest point or multiple points if ditance to all of them is the same...
+    //
+    pub fn find_closest_points<'a>(&self, points: &'a [Point]) -> Vec<&'a Point> {
+        let mut closest_points = vec![]; // HashSet maybe?
+        let mut closest_distance = u32::MAX;
+
+        for candidate in points {
+            let distance = self.rectilinear_distance(candidate);
+
+            if distance < closest_distance {
+                closest_distance = distance;
+                closest_points.clear(); // better candidate found!
+                closest_points.push(candidate);
+            }
+
+            if distance == closest_distance && !closest_points.contains(&candidate) {
+                closest_points.push(candidate);
+            }
+        }
+
+        closest_points
+    }
+}
+
+/// Map of the coordinates with the areas closest to each of them and the
+/// region close to all of them
+pub struct World {
+    points: Vec<Point>,
+    map: Grid<String>,
+    proximity_map: Grid<String>,
+    closest_map: Grid<String>,
+}
+
+impl World {
+    /// Every location from 0,0 up to but excluding `width`,`height` is mapped,
+    /// the region holds the locations with a total distance to all points
+    /// below `max_distance`
+    pub fn new(points: Vec<Point>, width: usize, height: usize, max_distance: u32) -> World {
+        let mut map = Grid::new(width, height, String::from("."));
+        for point in points.iter() {
+            map.set(
+                point.position.x as i64,
+                point.position.y as i64,
+                point.id.clone(),
+            );
+        }
+
+        let proximity_map = proximity_map(&points, width, height);
+
+        // coordinates outside of the region keep their label
+        let mut closest_map = region_map(&points, width, height, max_distance);
+        for ((x, y), cell) in map.iter() {
+            if cell != "." && closest_map[(x, y)] != "#" {
+                closest_map[(x, y)] = cell.clone();
+            }
+        }
+
+        World {
+            points,
+            map,
+            proximity_map,
+            closest_map,
+        }
+    }
+
+    /// Size of the region within the total distance to all coordinates
+    pub fn closest_island_size(&self) -> u32 {
+        region_size(&self.closest_map)
+    }
+
+    /// Largest area that doesn't reach the edge of the map, areas are named by
+    /// the id of their coordinate. Coordinates all on the outline of the others
+    /// only have infinite areas.
+    pub fn find_biggest_island(&self) -> Result<Island, Error> {
+        biggest_island(&self.proximity_map)
+    }
+
+    // Proximity map and safe region of the puzzle input, coordinates are white
+    fn images(&self) -> Vec<(&'static str, Image)> {
+        let owners: HashMap<String, usize> = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| (point.id.to_ascii_lowercase(), idx))
+            .collect();
+
+        let mut proximity = Image::from_grid(&self.proximity_map, |cell| {
+            match owners.get(&cell.to_ascii_lowercase()) {
+                Some(&idx) => image::palette(idx),
+                None => image::BLACK,
+            }
+        });
+        let mut region = Image::from_grid(&self.closest_map, |cell| match cell.as_str() {
+            "#" => Rgb(60, 180, 75),
+            _ => Rgb(40, 40, 40),
+        });
+
+        for point in &self.points {
+            let (x, y) = (point.position.x as usize, point.position.y as usize);
+            proximity.set(x, y, image::WHITE);
+            region.set(x, y, image::WHITE);
+        }
+
+        vec![("proximity", proximity), ("region", region)]
+    }
+}
+
+// Id of the closest coordinate of every location, lower case unless the
+// coordinate is right there. Locations as close to several are ".".
+fn proximity_map(points: &[Point], width: usize, height: usize) -> Grid<String> {
+    Grid::from_fn(width, height, |x, y| {
+        let location = Point::new("", x as i32, y as i32);
+
+        match location.find_closest_points(points).as_slice() {
+            [point] if *point == &location => point.id.clone(),
+            [point] => point.id.to_ascii_lowercase(),
+            _ => String::from("."),
+        }
+    })
+}
+
+// Locations with a total distance to all coordinates below `max_distance` are
+// "#", the others "."
+fn region_map(points: &[Point], width: usize, height: usize, max_distance: u32) -> Grid<String> {
+    Grid::from_fn(width, height, |x, y| {
+        let location = Point::new("?", x as i32, y as i32);
+
+        if location.sum_distances(points) < max_distance {
+            String::from("#")
+        } else {
+            String::from(".")
+        }
+    })
+}
+
+fn region_size(region_map: &Grid<String>) -> u32 {
+    region_map.values().filter(|cell| *cell == "#").count() as u32
+}
+
+// See `World::find_biggest_island`
+fn biggest_island(proximity_map: &Grid<String>) -> Result<Island, Error> {
+    let mut unlimited_islands = HashSet::new();
+    let mut counts = HashMap::new();
+
+    unlimited_islands.insert(String::from("."));
+
+    for ((x, y), col) in proximity_map.iter() {
+        // border points belongs to unlimited islands
+        if proximity_map.on_edge(x, y) {
+            unlimited_islands.insert(col.to_ascii_lowercase());
+            continue;
+        }
+
+        counts
+            .entry(col.to_ascii_lowercase())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    let keys: HashSet<String> = counts.keys().cloned().collect();
+    let real_islands = keys.difference(&unlimited_islands);
+
+    let mut max_island = String::from(".");
+    let mut max_island_size = 0;
+
+    for key in real_islands {
+        if counts[key] > max_island_size {
+            max_island = key.clone();
+            max_island_size = counts[key];
+        }
+    }
+
+    utils::debug!("day6", "islands counted";
+        finite = keys.difference(&unlimited_islands).count(),
+        infinite = unlimited_islands.len() - 1, // "." is not an island
+        biggest = max_island, size = max_island_size);
+
+    if max_island_size == 0 {
+        return Err(Error::no_answer("every area is infinite"));
+    }
+    Ok(Island {
+        size: max_island_size,
+        point: max_island,
+    })
+}
+
+// Closest coordinates like in the description of part one, then the region
+// of part two. Coordinates are lettered A to Z in input order, the cells they
+// own are lower case.
+impl Render for World {
+    fn render(&self, canvas: &mut Canvas) {
+        let owners: HashMap<String, usize> = self
+            .points
+            .iter()
+            .enumerate()
+            .map(|(idx, point)| (point.id.to_ascii_lowercase(), idx))
+            .collect();
+        let letter = |idx: usize| (b'A' + (idx % 26) as u8) as char;
+        let max_x = self.map.max().0;
+
+        for ((x, y), cell) in self.proximity_map.iter() {
+            match owners.get(&cell.to_ascii_lowercase()) {
+                Some(&idx) if self.map[(x, y)] != "." => canvas.put(letter(idx), Color::Bold),
+                Some(&idx) => canvas.put(letter(idx).to_ascii_lowercase(), Color::cycle(idx)),
+                None => canvas.put('.', Color::Gray),
+            }
+            if x == max_x {
+                canvas.newline();
+            }
+        }
+
+        canvas.newline();
+
+        for ((x, y), cell) in self.closest_map.iter() {
+            match owners.get(&self.map[(x, y)].to_ascii_lowercase()) {
+                Some(&idx) => canvas.put(letter(idx), Color::Bold),
+                None if cell == "#" => canvas.put('#', Color::Green),
+                None => canvas.put('.', Color::Gray),
+            }
+            if x == max_x {
+                canvas.newline();
+            }
+        }
+    }
+}
+
+/// Coordinates of the input, named by their position in it
+pub struct Coordinates {
+    points: Vec<Point>,
+    bounds: Rect<i32>,
+}
+
+impl Coordinates {
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Smallest rectangle around the coordinates, `max` is exclusive
+    pub fn bounds(&self) -> Rect<i32> {
+        self.bounds
+    }
+
+    /// World from 0,0 to the bottom right point, with the region below
+    /// `max_distance`
+    pub fn world(&self, max_distance: u32) -> World {
+        let (width, height) = self.size();
+        World::new(self.points.clone(), width, height, max_distance)
+    }
+
+    /// Largest finite area, without the rest of the world
+    pub fn biggest_island(&self) -> Result<Island, Error> {
+        let (width, height) = self.size();
+        biggest_island(&proximity_map(&self.points, width, height))
+    }
+
+    /// Size of the region below `max_distance`, without the rest of the world
+    pub fn region_size(&self, max_distance: u32) -> u32 {
+        let (width, height) = self.size();
+        region_size(&region_map(&self.points, width, height, max_distance))
+    }
+
+    // From 0,0 to the bottom right point
+    fn size(&self) -> (usize, usize) {
+        (self.bounds.max.x as usize, self.bounds.max.y as usize)
+    }
+}
+
+/// Largest finite area and the coordinate it belongs to
+#[derive(Debug, PartialEq)]
+pub struct Island {
+    pub size: u32,
+    pub point: String,
+}
+
+impl fmt::Display for Island {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl Answer for Island {
+    fn diagnostics(&self) -> Vec<(&'static str, String)> {
+        vec![("point", self.point.clone())]
+    }
+}
+
+// Coordinates are drawn on a map starting at 0,0, it must fit in memory
+const MAX_COORDINATE: i32 = 1000;
+
+// 1, 6 - the id is left empty, points are named by `Day6::parse`
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Point, ParseError> {
+        let mut scanner = Scanner::new(line);
+        let coordinate = |scanner: &mut Scanner| {
+            scanner.skip_whitespace();
+            let before = scanner.error("a coordinate from 0 to 1000");
+            match scanner.number()? {
+                value @ 0..=MAX_COORDINATE => Ok(value),
+                _ => Err(before),
+            }
+        };
+
+        let x = coordinate(&mut scanner)?;
+        scanner.expect(",")?;
+        let y = coordinate(&mut scanner)?;
+        scanner.end()?;
+
+        Ok(Point::new("", x, y))
+    }
+}
+
+/// Total distance to all coordinates the region of the puzzle stays below
+pub const MAX_DISTANCE: u32 = 10_000;
+
+/// Parses one coordinate per line, from 0 to 1000
+#[derive(Debug, Clone)]
+pub struct Day6 {
+    /// Bound of the region of part two, `max_distance` in `aoc.toml`
+    pub max_distance: u32,
+}
+
+impl Default for Day6 {
+    fn default() -> Day6 {
+        Day6 {
+            max_distance: MAX_DISTANCE,
+        }
+    }
+}
+
+impl Configure for Day6 {
+    fn configure(params: &mut Params) -> Result<Day6, ConfigError> {
+        let max_distance = params.take("max_distance", MAX_DISTANCE)?;
+
+        Ok(Day6 { max_distance })
+    }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Chronal Coordinates";
+
+    type Input = Coordinates;
+    type Answer1 = Island;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Coordinates, Error> {
+        let points: Vec<Point> = parse_lines(input)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, point): (usize, Point)| point.with_id(&i.to_string()))
+            .collect();
+
+        let bounds = Rect::bounding_box(points.iter().map(|point| point.position))
+            .ok_or_else(|| ParseError::input("a coordinate", None))?;
+
+        Ok(Coordinates { points, bounds })
+    }
+
+    fn part_one(&self, coords: &Coordinates) -> Result<Island, Error> {
+        coords.biggest_island()
+    }
+
+    fn part_two(&self, coords: &Coordinates) -> Result<u32, Error> {
+        Ok(coords.region_size(self.max_distance))
+    }
+
+    fn visualize(&self, coords: &Coordinates, canvas: &mut Canvas) {
+        canvas.draw(&coords.world(self.max_distance));
+    }
+
+    fn images(&self, coords: &Coordinates) -> Vec<(&'static str, Image)> {
+        coords.world(self.max_distance).images()
+    }
+
+    //
+    // About 50 distinct coordinates between 40 and 360, like the real ones.
+    // The first one is boxed in by four others at the same distance above,
+    // below, left and right of it, which keeps its area finite whatever the
+    // other coordinates are, so part one always has an answer.
+    //
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let count = size.unwrap_or(50).max(5);
+
+        let (x, y) = (rng.between(140, 260), rng.between(140, 260));
+        let d = rng.between(10, 80);
+        let mut seen: BTreeSet<(i64, i64)> =
+            BTreeSet::from([(x, y), (x - d, y), (x + d, y), (x, y - d), (x, y + d)]);
+        let mut coords: Vec<_> = seen.iter().cloned().collect();
+
+        while coords.len() < count {
+            let coord = (rng.between(40, 360), rng.between(40, 360));
+            if seen.insert(coord) {
+                coords.push(coord);
+            }
+        }
+        rng.shuffle(&mut coords);
+
+        Some(
+            coords
+                .iter()
+                .map(|(x, y)| format!("{}, {}\n", x, y))
+                .collect(),
+        )
+    }
+}
+
+// --- Part Two ---
+//
+// On the other hand, if the coordinates are safe, maybe the best you can do is
+// try to find a region near as many coordinates as possible.
+//
+// For example, suppose you want the sum of the Manhattan distance to all of the
+// coordinates to be less than 32. For each location, add up the distances to
+// all of the given coordinates; if the total of those distances is less than
+// 32, that location is within the desired region. Using the same coordinates as
+// above, the resulting region looks like this:
+//
+// ..........
+// .A........
+// ..........
+// ...###..C.
+// ..#D###...
+// ..###E#...
+// .B.###....
+// ..........
+// ..........
+// ........F.
+//
+// In particular, consider the highlighted location 4,3 located at the top
+// middle of the region. Its calculation is as follows, where abs() is the
+// absolute value function:
+//
+//     Distance to coordinate A: abs(4-1) + abs(3-1) =  5
+//     Distance to coordinate B: abs(4-1) + abs(3-6) =  6
+//     Distance to coordinate C: abs(4-8) + abs(3-3) =  4
+//     Distance to coordinate D: abs(4-3) + abs(3-4) =  2
+//     Distance to coordinate E: abs(4-5) + abs(3-5) =  3
+//     Distance to coordinate F: abs(4-8) + abs(3-9) = 10
+//     Total distance: 5 + 6 + 4 + 2 + 3 + 10 = 30
+//
+// Because the total distance to all coordinates (30) is less than 32, the
+// location is within the region.
+//
+// This region, which also includes coordinates D and E, has a total size of 16.
+//
+// Your actual region will need to be much larger than this example, though,
+// instead including all locations with a total distance of less than 10000.
+//
+// What is the size of the region containing all locations which have a total
+// distance to all given coordinates of less than 10000?
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use utils::property;
+
+    // Every cell is named after its single nearest coordinate, "." on ties
+    fn labels_nearest_point(coords: &[(usize, usize)]) -> Result<(), String> {
+        // coordinates of the puzzle are distinct
+        let mut seen = HashSet::new();
+        let input: String = coords
+            .iter()
+            .filter(|coord| seen.insert(*coord))
+            .map(|(x, y)| format!("{}, {}\n", x, y))
+            .collect();
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        let coords = Day6::default()
+            .parse(&input)
+            .map_err(|errors| errors.to_string())?;
+        let world = coords.world(MAX_DISTANCE);
+
+        for ((x, y), label) in world.proximity_map.iter() {
+            let cell = Point::new("", x as i32, y as i32);
+            let distances: Vec<u32> = coords
+                .points
+                .iter()
+                .map(|point| cell.rectilinear_distance(point))
+                .collect();
+            let nearest = *distances.iter().min().unwrap();
+            let ids: Vec<&str> = coords
+                .points
+                .iter()
+                .zip(&distances)
+                .filter(|(_, distance)| **distance == nearest)
+                .map(|(point, _)| point.id.as_str())
+                .collect();
+
+            let expected = if ids.len() == 1 { ids[0] } else { "." };
+            if label != expected {
+                return Err(format!("{},{} is {:?}, nearest {:?}", x, y, label, ids));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_proximity_map_labels_nearest_point() {
+        let coordinates = |rng: &mut Rng, size: usize| {
+            property::vec_of(rng, size.min(12), |rng| (rng.below(16), rng.below(16)))
+        };
+
+        property::check("nearest point", coordinates, |coords: &Vec<_>| {
+            labels_nearest_point(coords)
+        });
+    }
+
+    #[test]
+    fn test_parse_point() {
+        assert_eq!(" 8, 3".parse(), Ok(Point::new("", 8, 3)));
+        assert_eq!(
+            "8 3".parse::<Point>(),
+            Err(ParseError::new(3, "\",\"", Some("3")))
+        );
+
+        let errors = parse_lines::<Point>("1, 1\n1; 6\n8, 3\n3,\n").unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "line 2, column 2: expected \",\", found \";\"\n\
+             line 4, column 3: expected a number, found end of line"
+        );
+        assert_eq!(
+            "1, 1001".parse::<Point>(),
+            Err(ParseError::new(
+                4,
+                "a coordinate from 0 to 1000",
+                Some("1001")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rectilinear_distance() {
+        let a = Point::new("A", 1, 1);
+        let b = Point::new("B", 1, 6);
+
+        assert_eq!(5, a.rectilinear_distance(&b));
+    }
+
+    #[test]
+    fn test_find_closest_points_one_candidate() {
+        let points = vec![Point::new("A", 0, 0)];
+
+        let point = Point::new("B", 1, 1); // both A and C equally close
+        let closest = point.find_closest_points(&points);
+        assert_eq!(1, closest.len());
+        assert!(closest.contains(&&points[0]));
+    }
+
+    #[test]
+    fn test_find_closest_points_two_candidates() {
+        // A..
+        // ..C
+        let points = vec![
+            Point::new("A", 0, 0),
+            // Point::new("B", 1, 1),
+            Point::new("C", 2, 2),
+        ];
+
+        let point = Point::new("B", 1, 1); // both A and C equally close
+        let closest = point.find_closest_points(&points);
+
+        assert_eq!(2, closest.len());
+        assert!(closest.contains(&&points[0]));
+        assert!(closest.contains(&&points[1]));
+    }
+
+    #[test]
+    fn test_world_build_proximity_map() {
+        let points = vec![
+            Point::new("A", 1, 1),
+            Point::new("B", 1, 6),
+            Point::new("C", 8, 3),
+            Point::new("D", 3, 4),
+            Point::new("E", 5, 5),
+            Point::new("F", 8, 9),
+        ];
+
+        let world = World::new(points, 10, 10, MAX_DISTANCE);
+
+        let proximity_map_str = world.proximity_map.to_string();
+
+        let expected_proximity_map_str = indoc![
+            "
+                aaaaa.cccc
+                aAaaa.cccc
+                aaaddecccc
+                aadddeccCc
+                ..dDdeeccc
+                bb.deEeecc
+                bBb.eeee..
+                bbb.eeefff
+                bbb.eeffff
+                bbb.ffffFf
+            "
+        ];
+
+        assert_eq!(proximity_map_str, expected_proximity_map_str);
+    }
+
+    #[test]
+    fn test_render_world() {
+        let coords = Day6::default()
+            .parse("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n")
+            .unwrap();
+        let world = World::new(coords.points, 10, 10, MAX_DISTANCE);
+
+        let expected_proximity_map_str = indoc![
+            "
+                aaaaa.cccc
+                aAaaa.cccc
+                aaaddecccc
+                aadddeccCc
+                ..dDdeeccc
+                bb.deEeecc
+                bBb.eeee..
+                bbb.eeefff
+                bbb.eeffff
+                bbb.ffffFf
+
+            "
+        ];
+
+        assert!(world.to_plain().starts_with(expected_proximity_map_str));
+    }
+
+    #[test]
+    fn test_world_find_biggest_island() {
+        let points = vec![
+            Point::new("A", 1, 1),
+            Point::new("B", 1, 6),
+            Point::new("C", 8, 3),
+            Point::new("D", 3, 4),
+            Point::new("E", 5, 5),
+            Point::new("F", 8, 9),
+        ];
+
+        let world = World::new(points, 10, 10, MAX_DISTANCE);
+
+        let biggest_island = world.find_biggest_island().unwrap();
+
+        // aaaaa.cccc
+        // aAaaa.cccc
+        // aaaddecccc
+        // aadddeccCc
+        // ..dDdeeccc
+        // bb.deEeecc
+        // bBb.eeee..
+        // bbb.eeefff
+        // bbb.eeffff
+        // bbb.ffffFf
+
+        assert_eq!("e".to_string(), biggest_island.point);
+        assert_eq!(17, biggest_island.size);
+    }
+
+    #[test]
+    fn test_parts_without_world() {
+        let day = Day6 { max_distance: 32 };
+        let coords = day.parse("1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n").unwrap();
+        let world = coords.world(32);
+
+        assert_eq!(
+            day.part_one(&coords).unwrap(),
+            world.find_biggest_island().unwrap()
+        );
+        assert_eq!(day.part_two(&coords).unwrap(), world.closest_island_size());
+        assert_eq!(day.part_two(&coords).unwrap(), 16);
+    }
+
+    #[test]
+    // Using data from requirements...
+    fn test_map_distances() {
+        let mut map = vec![vec![String::from("."); 10]; 10]; // 10x10
+
+        let points = vec![
+            Point::new("A", 1, 1),
+            Point::new("B", 1, 6),
+            Point::new("C", 8, 3),
+            Point::new("D", 3, 4),
+            Point::new("E", 5, 5),
+            Point::new("F", 8, 9),
+        ];
+
+        for point in points.iter() {
+            map[point.position.y as usize][point.position.x as usize] = point.id.clone();
+        }
+
+        let mut map_str = String::new();
+        for row in &map {
+            for col in row {
+                map_str.push_str(col);
+            }
+            map_str.push('\n');
+        }
+
+        let mut proximity_map_str = String::new();
+
+        for (i, row) in map.iter().enumerate() {
+            for (j, col) in row.iter().enumerate() {
+                if col != "." {
+                    proximity_map_str.push_str(col);
+                    continue;
+                }
+
+                let point = Point::new("", j as i32, i as i32);
+                let closest = point.find_closest_points(&points);
+
+                if closest.len() > 1 {
+                    proximity_map_str.push('.');
+                } else if closest.len() == 1 {
+                    let p = closest[0];
+                    proximity_map_str.push_str(&p.id.to_ascii_lowercase());
+                }
+            }
+            proximity_map_str.push('\n');
+        }
+
+        let expected_map_str = indoc![
+            "
+                ..........
+                .A........
+                ..........
+                ........C.
+                ...D......
+                .....E....
+                .B........
+                ..........
+                ..........
+                ........F.
+            "
+        ];
+
+        let expected_proximity_map_str = indoc![
+            "
+                aaaaa.cccc
+                aAaaa.cccc
+                aaaddecccc
+                aadddeccCc
+                ..dDdeeccc
+                bb.deEeecc
+                bBb.eeee..
+                bbb.eeefff
+                bbb.eeffff
+                bbb.ffffFf
+            "
+        ];
+
+        assert_eq!(expected_map_str, map_str);
+        assert_eq!(expected_proximity_map_str, proximity_map_str);
+    }
+
+    #[test]
+    fn test_sum_distances_to_all_points() {
+        let test_points = vec![
+            Point::new("A", 1, 1),
+            Point::new("B", 1, 6),
+            Point::new("C", 8, 3),
+            Point::new("D", 3, 4),
+            Point::new("E", 5, 5),
+            Point::new("F", 8, 9),
+        ];
+
+        let some_point = Point::new("#", 4, 3);
+
+        let sum = some_point.sum_distances(&test_points);
+
+        assert_eq!(sum, 30)
+    }
+
+    #[test]
+    fn test_build_closest_map() {
+        let test_points = vec![
+            Point::new("A", 1, 1),
+            Point::new("B", 1, 6),
+            Point::new("C", 8, 3),
+            Point::new("D", 3, 4),
+            Point::new("E", 5, 5),
+            Point::new("F", 8, 9),
+        ];
+        let world = World::new(test_points, 10, 10, 32);
+
+        let closest_map_str = world.closest_map.to_string();
+
+        // D and E are within the region too, the puzzle only draws them as letters
+        let expected_closest_map_str = indoc![
+            "
+                ..........
+                .A........
+                ..........
+                ...###..C.
+                ..#####...
+                ..#####...
+                .B.###....
+                ..........
+                ..........
+                ........F.
+            "
+        ];
+
+        assert_eq!(expected_closest_map_str, closest_map_str);
+
+        let closest_island_size = world.closest_island_size();
+        assert_eq!(closest_island_size, 16);
+    }
+}
@@ -0,0 +1,997 @@
+// --- Day 7: The Sum of Its Parts ---
+//
+// You find yourself standing on a snow-covered coastline; apparently, you
+// landed a little off course. The region is too hilly to see the North Pole
+// from here, but you do spot some Elves that seem to be trying to unpack
+// something that washed ashore. It's quite cold out, so you decide to risk
+// creating a paradox by asking them for directions.
+//
+// "Oh, are you the search party?" Somehow, you can understand whatever Elves
+// from the year 1018 speak; you assume it's Ancient Nordic Elvish. Could the
+// device on your wrist also be a translator? "Those clothes don't look very
+// warm; take this." They hand you a heavy coat.
+//
+// "We do need to find our way back to the North Pole, but we have higher
+// priorities at the moment. You see, believe it or not, this box contains
+// something that will solve all of Santa's transportation problems - at least,
+// that's what it looks like from the pictures in the instructions." It doesn't
+// seem like they can read whatever language it's in, but you can: "Sleigh kit.
+// Some assembly required."
+//
+// "'Sleigh'? What a wonderful name! You must help us assemble this 'sleigh' at
+// once!" They start excitedly pulling more parts out of the box.
+//
+// The instructions specify a series of steps and requirements about which steps
+// must be finished before others can begin (your puzzle input). Each step is
+// designated by a single letter. For example, suppose you have the following
+// instructions:
+//
+// Step C must be finished before step A can begin.
+// Step C must be finished before step F can begin.
+// Step A must be finished before step B can begin.
+// Step A must be finished before step D can begin.
+// Step B must be finished before step E can begin.
+// Step D must be finished before step E can begin.
+// Step F must be finished before step E can begin.
+//
+// Visually, these requirements look like this:
+//
+//   -->A--->B--
+//  /    \      \
+// C      -->D--->E
+//  \           /
+//   ---->F-----
+//
+// Your first goal is to determine the order in which the steps should be
+// completed. If more than one step is ready, choose the step which is first
+// alphabetically. In this example, the steps would be completed as follows:
+//
+// - Only C is available, and so it is done first.
+// - Next, both A and F are available. A is first alphabetically, so it is done
+// - next.
+// - Then, even though F was available earlier, steps B and D are now also
+//   available, and B is the first alphabetically of the three.
+// - After that, only D and F are available. E is not available because only some
+//   of its prerequisites are complete. Therefore, D is completed next.
+// - F is the only choice, so it is done next.
+// - Finally, E is completed.
+//
+// So, in this example, the correct order is CABDFE.
+//
+// In what order should the steps in your instructions be completed?
+//
+
+// --- Part Two ---
+//
+// As you're about to begin construction, four of the Elves offer to help. "The sun
+// will set soon; it'll go faster if we work together." Now, you need to account
+// for multiple people working on steps simultaneously. If multiple steps are
+// available, workers should still begin them in alphabetical order.
+//
+// Each step takes 60 seconds plus an amount corresponding to its letter: A=1, B=2,
+// C=3, and so on. So, step A takes 60+1=61 seconds, while step Z takes 60+26=86
+// seconds. No time is required between steps.
+//
+// To simplify things for the example, however, suppose you only have help from one
+// Elf (a total of two workers) and that each step takes 60 fewer seconds (so that
+// step A takes 1 second and step Z takes 26 seconds). Then, using the same
+// instructions as above, this is how each second would be spent:
+//
+// Second   Worker 1   Worker 2   Done
+//    0        C          .
+//    1        C          .
+//    2        C          .
+//    3        A          F       C
+//    4        B          F       CA
+//    5        B          F       CA
+//    6        D          F       CAB
+//    7        D          F       CAB
+//    8        D          F       CAB
+//    9        D          .       CABF
+//   10        E          .       CABFD
+//   11        E          .       CABFD
+//   12        E          .       CABFD
+//   13        E          .       CABFD
+//   14        E          .       CABFD
+//   15        .          .       CABFDE
+//
+// Each row represents one second of time. The Second column identifies how many
+// seconds have passed as of the beginning of that second. Each worker column shows
+// the step that worker is currently doing (or . if they are idle). The Done column
+// shows completed steps.
+//
+// Note that the order of the steps has changed; this is because steps now take
+// time to finish and multiple workers can begin multiple steps simultaneously.
+//
+// In this example, it would take 15 seconds for two workers to complete these
+// steps.
+//
+// With 5 workers and the 60+ second step durations described above, how long will
+// it take to complete all of the steps?
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use utils::config::Value;
+use utils::parse::{ParseErrors, Scanner};
+use utils::{
+    parse_lines, Answer, Canvas, Color, ConfigError, Configure, Error, Graph, Params, ParseError,
+    Render, Rng, Solution,
+};
+
+/// Requirement of the instructions, `to` can't begin before `from` is done
+#[derive(Clone, Debug)]
+pub struct Edge {
+    from: Letter,
+    to: Letter,
+}
+
+// Step C must be finished before step A can begin.
+impl FromStr for Edge {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Edge, ParseError> {
+        let step = |scanner: &mut Scanner| {
+            scanner.char_if("a step letter", |chr| chr.is_ascii_alphabetic())
+        };
+        let mut scanner = Scanner::new(line);
+
+        scanner.expect("Step")?;
+        let from = step(&mut scanner)?;
+        scanner.expect("must be finished before step")?;
+        let to = step(&mut scanner)?;
+        scanner.expect("can begin.")?;
+        scanner.end()?;
+
+        Ok(Edge {
+            from: Letter::from_char(from),
+            to: Letter::from_char(to),
+        })
+    }
+}
+
+/// Steps with an edge to every step waiting for them, edges are also kept in
+/// input order. Parsing rejects steps waiting on each other in a cycle.
+#[derive(Debug, Clone)]
+pub struct DAG {
+    edges: Vec<Edge>,
+    graph: Graph<Letter>,
+}
+
+impl FromStr for DAG {
+    type Err = ParseErrors;
+
+    fn from_str(input: &str) -> Result<DAG, ParseErrors> {
+        let mut dag = DAG::new();
+
+        for edge in parse_lines(input)? {
+            dag.add_edge(edge);
+        }
+
+        // with a cycle there is always a step left that can't begin
+        if dag.graph.topological_sort().is_none() {
+            return Err(ParseError::input(
+                "steps that can all be done",
+                Some("steps waiting on each other in a cycle"),
+            )
+            .into());
+        }
+
+        Ok(dag)
+    }
+}
+
+impl DAG {
+    pub fn new() -> Self {
+        Self {
+            edges: vec![],
+            graph: Graph::new(),
+        }
+    }
+
+    pub fn add_edge(&mut self, edge: Edge) {
+        self.edges.push(edge.clone());
+        self.graph.add_edge(edge.from, edge.to);
+    }
+
+    // Removes all root nodes at once, round after round, so independent
+    // steps keep their alphabetical order within a round
+    pub fn topological_sort(&mut self) -> Vec<Letter> {
+        let mut output = Vec::new();
+        while let Some(root_nodes) = self.find_root_nodes() {
+            for node in root_nodes {
+                self.complete(node);
+                output.push(node);
+            }
+        }
+        output
+    }
+
+    /// Steps in the order a single worker would do them, the first available
+    /// step in alphabetical order is always done next
+    pub fn aoc_sort(&self) -> Result<Vec<Letter>, Error> {
+        self.graph
+            .topological_sort()
+            .ok_or_else(|| Error::no_answer("steps wait on each other in a cycle"))
+    }
+
+    pub fn complete(&mut self, node: Letter) {
+        self.graph.remove_node(&node);
+    }
+
+    // Root nodes have no incoming edges, sorted alphabetically
+    pub fn find_root_nodes(&self) -> Option<Vec<Letter>> {
+        let roots: Vec<Letter> = self.graph.roots().into_iter().cloned().collect();
+
+        if roots.is_empty() {
+            None
+        } else {
+            Some(roots)
+        }
+    }
+
+    pub fn next_root(&self) -> Option<Letter> {
+        self.find_root_nodes().map(|roots| roots[0])
+    }
+}
+
+impl Default for DAG {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for DAG {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "DAG: [")?;
+        for node in self.graph.nodes() {
+            let incoming = self
+                .graph
+                .predecessors(node)
+                .map(|l| l.as_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            writeln!(f, "  {} -> [{}]", node, incoming)?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+/// Step of the instructions, with the seconds it takes and has been worked on
+#[derive(Debug, Clone, Eq, Copy)]
+pub struct Letter {
+    char: char,
+    seconds: usize,
+    in_progress: usize,
+}
+
+impl PartialEq for Letter {
+    fn eq(&self, other: &Self) -> bool {
+        self.char == other.char
+    }
+}
+
+// Steps are ordered alphabetically, progress is ignored like in `eq`
+impl Ord for Letter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.char.cmp(&other.char)
+    }
+}
+
+impl PartialOrd for Letter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for Letter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.char.hash(state);
+    }
+}
+
+impl fmt::Display for Letter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Letter(char: {}, in_progress: {}, seconds: {}, done: {})",
+            self.char,
+            self.in_progress,
+            self.seconds,
+            self.is_done()
+        )
+    }
+}
+
+impl Letter {
+    pub fn from_char(char: char) -> Letter {
+        Self {
+            char: char.to_ascii_lowercase(),
+            seconds: 0,
+            in_progress: 0,
+        }
+        .with_base(BASE_SECONDS)
+    }
+
+    /// Same step taking `base_seconds` plus its place in the alphabet
+    pub fn with_base(self, base_seconds: usize) -> Letter {
+        Self {
+            seconds: base_seconds + (self.char as usize - 'a' as usize) + 1,
+            ..self
+        }
+    }
+
+    /// Lower case letter of the step
+    pub fn char(&self) -> char {
+        self.char
+    }
+
+    fn as_string(&self) -> String {
+        String::from(self.char)
+    }
+
+    pub fn is_done(self) -> bool {
+        self.seconds == self.in_progress
+    }
+}
+
+/// Workers doing the steps of a DAG together, a second at a time
+#[derive(Debug, Clone)]
+pub struct AssemblyLine {
+    workers: Vec<Option<Letter>>,
+    // seconds every step takes before its letter's own
+    base_seconds: usize,
+    dag: DAG,
+    seconds: usize,
+    completed: Vec<Letter>,
+    // second, step of every worker and steps done so far, one row per second,
+    // only kept for rendering
+    record_timeline: bool,
+    timeline: Vec<(usize, Vec<Option<char>>, String)>,
+}
+
+impl AssemblyLine {
+    pub fn from_dag(dag: DAG, workers_count: usize, base_seconds: usize) -> AssemblyLine {
+        Self {
+            workers: vec![None; workers_count],
+            base_seconds,
+            dag,
+            seconds: 0,
+            completed: Vec::new(),
+            record_timeline: false,
+            timeline: Vec::new(),
+        }
+    }
+
+    /// Keeps what every worker does each second, the table `Render` draws
+    pub fn with_timeline(self) -> AssemblyLine {
+        Self {
+            record_timeline: true,
+            ..self
+        }
+    }
+
+    /// Seconds the work has taken so far
+    pub fn seconds(&self) -> usize {
+        self.seconds
+    }
+
+    /// Steps done so far, in the order they were completed
+    pub fn completed(&self) -> &[Letter] {
+        &self.completed
+    }
+
+    fn letters_to_string(letters: Vec<Letter>) -> String {
+        letters
+            .iter()
+            .map(|l| l.char.to_ascii_uppercase())
+            .collect()
+    }
+
+    pub fn assign(&mut self, letter: Option<Letter>, idx: usize) {
+        let letter = letter.map(|letter| letter.with_base(self.base_seconds));
+        self.workers[idx] = letter;
+        if let Some(letter) = letter {
+            utils::debug!("day7", "step assigned";
+                second = self.seconds, worker = idx, step = letter.char.to_ascii_uppercase());
+        }
+    }
+
+    pub fn unassign(&mut self, idx: usize) {
+        if let Some(letter) = self.workers[idx] {
+            utils::debug!("day7", "worker freed";
+                second = self.seconds, worker = idx, step = letter.char.to_ascii_uppercase());
+        }
+        self.workers[idx] = None
+    }
+
+    pub fn next_step(&self) -> Option<Letter> {
+        if let Some(roots) = self.dag.find_root_nodes() {
+            let in_progress: Vec<Letter> = self.workers.iter().flatten().cloned().collect();
+            let valid_steps: Vec<Letter> = roots
+                .iter()
+                .filter(|letter| !in_progress.contains(*letter))
+                .cloned()
+                .collect();
+            utils::trace!("day7", "steps available";
+                second = self.seconds, steps = Self::letters_to_string(valid_steps.clone()));
+            return valid_steps.first().cloned();
+        }
+        None
+    }
+
+    fn complete(&mut self, letter: Letter) {
+        self.completed.push(letter);
+        self.dag.complete(letter);
+        utils::debug!("day7", "step completed";
+            second = self.seconds, step = letter.char.to_ascii_uppercase(),
+            done = Self::letters_to_string(self.completed.clone()));
+    }
+
+    pub fn tick(&mut self) -> Vec<(usize, Letter)> {
+        let mut completed: Vec<(usize, Letter)> = vec![];
+        for (idx, letter) in self.workers.iter_mut().enumerate() {
+            match letter {
+                Some(step) => {
+                    step.in_progress += 1;
+                    if step.is_done() {
+                        completed.push((idx, *step));
+                    }
+                }
+                None => utils::trace!("day7", "worker idle"; second = self.seconds, worker = idx),
+            }
+        }
+
+        self.seconds += 1;
+        completed
+    }
+
+    pub fn free_workers(&self) -> Vec<usize> {
+        let mut free = vec![];
+        for (idx, worker) in self.workers.iter().enumerate() {
+            if worker.is_none() {
+                free.push(idx);
+            }
+        }
+        free
+    }
+
+    fn is_complete(&self) -> bool {
+        let all_workers_are_free = self.workers.iter().all(|w| w.is_none());
+        all_workers_are_free && self.next_step().is_none()
+    }
+
+    fn record(&mut self) {
+        if !self.record_timeline {
+            return;
+        }
+
+        let steps = self
+            .workers
+            .iter()
+            .map(|worker| worker.map(|letter| letter.char.to_ascii_uppercase()))
+            .collect();
+        let done = Self::letters_to_string(self.completed.clone());
+
+        self.timeline.push((self.seconds, steps, done));
+    }
+
+    /// Works until every step is done
+    pub fn process(&mut self) {
+        while !self.is_complete() {
+            // assign work to free workers
+            for idx in self.free_workers() {
+                let step = self.next_step();
+                self.assign(step, idx);
+            }
+            self.record();
+            // time forward
+            let completed = self.tick();
+            // unassign completed from workers
+            for (idx, letter) in completed {
+                self.complete(letter);
+                self.unassign(idx);
+            }
+        }
+        self.record();
+        utils::info!("day7", "assembly finished";
+            seconds = self.seconds, workers = self.workers.len());
+    }
+}
+
+// Worker table from the description of part two, one row per second
+impl Render for AssemblyLine {
+    fn render(&self, canvas: &mut Canvas) {
+        let mut header = String::from("Second");
+        for idx in 1..=self.workers.len() {
+            header.push_str(&format!("   Worker {}", idx));
+        }
+        header.push_str("   Done");
+        canvas.line(&header);
+
+        // steps sit under the middle of their "Worker n" column
+        for (second, steps, done) in &self.timeline {
+            canvas.text(&format!("{:>4}", second));
+            for (idx, step) in steps.iter().enumerate() {
+                canvas.text(if idx == 0 { "        " } else { "          " });
+                match step {
+                    Some(step) => canvas.put(*step, Color::cycle(*step as usize)),
+                    None => canvas.put('.', Color::Gray),
+                }
+            }
+            if !done.is_empty() {
+                canvas.text("       ");
+                canvas.colored(done, Color::Green);
+            }
+            canvas.newline();
+        }
+    }
+}
+
+/// Time taken by the assembly line and the order steps were completed in
+#[derive(Debug, PartialEq)]
+pub struct Assembly {
+    pub seconds: usize,
+    pub workers: usize,
+    pub order: String,
+}
+
+impl fmt::Display for Assembly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.seconds)
+    }
+}
+
+impl Answer for Assembly {
+    fn diagnostics(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("workers", self.workers.to_string()),
+            ("order", self.order.clone()),
+        ]
+    }
+}
+
+/// Seconds every step of the puzzle takes before its letter's own
+pub const BASE_SECONDS: usize = 60;
+
+/// Workers of the puzzle doing the steps of part two
+pub const WORKERS: usize = 5;
+
+/// Largest `base_seconds` of the config, an hour a step keeps the
+/// second by second simulation quick
+pub const MAX_BASE_SECONDS: usize = 3600;
+
+/// Largest `workers` of the config, there are no more steps than letters
+pub const MAX_WORKERS: usize = 26;
+
+/// Parses the instructions into a DAG of steps
+#[derive(Debug, Clone)]
+pub struct Day7 {
+    /// Seconds of a step before its letter's own, `base_seconds` in `aoc.toml`
+    pub base_seconds: usize,
+    /// Workers doing the steps together, `workers` in `aoc.toml`
+    pub workers: usize,
+}
+
+impl Default for Day7 {
+    fn default() -> Day7 {
+        Day7 {
+            base_seconds: BASE_SECONDS,
+            workers: WORKERS,
+        }
+    }
+}
+
+impl Configure for Day7 {
+    fn configure(params: &mut Params) -> Result<Day7, ConfigError> {
+        let base_seconds = params.take("base_seconds", BASE_SECONDS)?;
+        if base_seconds > MAX_BASE_SECONDS {
+            let found = Value::Int(base_seconds as i64);
+            return Err(params.invalid("base_seconds", "from 0 to 3600", found));
+        }
+        let workers = params.take("workers", WORKERS)?;
+        if !(1..=MAX_WORKERS).contains(&workers) {
+            let found = Value::Int(workers as i64);
+            return Err(params.invalid("workers", "from 1 to 26", found));
+        }
+
+        Ok(Day7 {
+            base_seconds,
+            workers,
+        })
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Sum of Its Parts";
+
+    type Input = DAG;
+    type Answer1 = String;
+    type Answer2 = Assembly;
+
+    fn parse(&self, input: &str) -> Result<DAG, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, dag: &DAG) -> Result<String, Error> {
+        Ok(AssemblyLine::letters_to_string(dag.aoc_sort()?))
+    }
+
+    fn part_two(&self, dag: &DAG) -> Result<Assembly, Error> {
+        assemble(dag, self.workers, self.base_seconds)
+    }
+
+    fn visualize(&self, dag: &DAG, canvas: &mut Canvas) {
+        let mut assembly_line =
+            AssemblyLine::from_dag(dag.clone(), self.workers, self.base_seconds).with_timeline();
+        assembly_line.process();
+
+        canvas.draw(&assembly_line);
+    }
+
+    //
+    // About 100 requirements between up to 26 steps. Edges only go from an
+    // earlier to a later step of a shuffled alphabet, so the steps never wait
+    // for each other in a cycle. Every step but the first waits for some
+    // earlier step, the remaining edges are random.
+    //
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut order: Vec<char> = ('A'..='Z').collect();
+        rng.shuffle(&mut order);
+
+        let steps = (size.unwrap_or(100).max(1) + 1).min(order.len());
+        let count = size.unwrap_or(100).clamp(1, steps * (steps - 1) / 2);
+
+        let mut edges: BTreeSet<(usize, usize)> =
+            (1..steps).map(|to| (rng.below(to), to)).collect();
+        while edges.len() < count {
+            let (one, other) = (rng.below(steps), rng.below(steps));
+            if one != other {
+                edges.insert((one.min(other), one.max(other)));
+            }
+        }
+
+        let mut lines: Vec<String> = edges
+            .into_iter()
+            .map(|(from, to)| {
+                format!(
+                    "Step {} must be finished before step {} can begin.\n",
+                    order[from], order[to]
+                )
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+
+        Some(lines.concat())
+    }
+}
+
+/// Seconds `workers` take to do every step of `dag` together, a step taking
+/// `base_seconds` plus its place in the alphabet
+pub fn assemble(dag: &DAG, workers: usize, base_seconds: usize) -> Result<Assembly, Error> {
+    if workers == 0 {
+        return Err(Error::no_answer("no workers to do the steps"));
+    }
+
+    let mut assembly_line = AssemblyLine::from_dag(dag.clone(), workers, base_seconds);
+    assembly_line.process();
+
+    // steps of a cycle never become available
+    if assembly_line.completed.len() < dag.graph.node_count() {
+        return Err(Error::no_answer("steps wait on each other in a cycle"));
+    }
+    Ok(Assembly {
+        seconds: assembly_line.seconds,
+        workers,
+        order: AssemblyLine::letters_to_string(assembly_line.completed),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use utils::property;
+    use utils::trace::{self, Level};
+    use utils::Config;
+
+    fn dag_input() -> DAG {
+        let data = utils::load_puzzle_input_from(7, &crate::inputs_dirs()).unwrap();
+        data.parse().unwrap()
+    }
+
+    fn str_to_letters(string: &str) -> Vec<Letter> {
+        string.chars().map(Letter::from_char).collect()
+    }
+
+    fn dag_fixture() -> DAG {
+        // Visually, these requirements look like this:
+        //
+        //   -->A--->B--
+        //  /    \      \
+        // C      -->D----->E
+        //  \           /
+        //   ---->F-----
+        //
+        "
+            Step C must be finished before step A can begin.
+            Step C must be finished before step F can begin.
+            Step A must be finished before step B can begin.
+            Step A must be finished before step D can begin.
+            Step B must be finished before step E can begin.
+            Step D must be finished before step E can begin.
+            Step F must be finished before step E can begin.
+            "
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_root_nodes() {
+        let dag = dag_fixture();
+        assert_eq!(str_to_letters("C"), dag.find_root_nodes().unwrap());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let dag: DAG = "Step R must be finished before step Y can begin.
+            Step X must be finished before step Y can begin."
+            .parse()
+            .unwrap();
+
+        let res0 = dag.edges[0].clone();
+        let res1 = dag.edges[1].clone();
+
+        assert_eq!(res0.from, Letter::from_char('R'));
+        assert_eq!(res0.to, Letter::from_char('Y'));
+        assert_eq!(res1.from, Letter::from_char('X'));
+        assert_eq!(res1.to, Letter::from_char('Y'));
+
+        let errors = "Step R must be finished before step Y can begin.
+            Step 4 must be finished before step Y can begin.
+            Step X must be finished after step Y can begin."
+            .parse::<DAG>()
+            .unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "line 2, column 18: expected a step letter, found \"4\"\n\
+             line 3, column 20: expected \"must be finished before step\", found \"must\""
+        );
+
+        let errors = "Step A must be finished before step B can begin.
+            Step B must be finished before step a can begin."
+            .parse::<DAG>()
+            .unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "input: expected steps that can all be done, \
+             found \"steps waiting on each other in a cycle\""
+        );
+    }
+
+    #[test]
+    fn test_aoc_sort() {
+        // example input
+        let dag = dag_fixture();
+        let res = dag.aoc_sort().unwrap();
+        assert_eq!(res, str_to_letters("CABDFE"));
+
+        // my input
+        let dag = dag_input();
+        let res = dag.aoc_sort().unwrap();
+        assert_eq!(res, str_to_letters("CFMNLOAHRKPTWBJSYZVGUQXIDE"));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        // DAGs built by hand aren't checked for cycles like parsed ones
+        let mut dag = dag_fixture();
+        dag.add_edge(Edge {
+            from: Letter::from_char('E'),
+            to: Letter::from_char('C'),
+        });
+
+        let cycle = "no answer: steps wait on each other in a cycle";
+        assert_eq!(dag.aoc_sort().unwrap_err().to_string(), cycle);
+        assert_eq!(assemble(&dag, 2, 0).unwrap_err().to_string(), cycle);
+        assert_eq!(
+            assemble(&dag_fixture(), 0, 0).unwrap_err().to_string(),
+            "no answer: no workers to do the steps"
+        );
+        assert_eq!(
+            assemble(&dag_fixture(), 2, BASE_SECONDS).unwrap().order,
+            "CAFBDE"
+        );
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut dag = dag_fixture();
+        let sorted = dag.topological_sort();
+        assert_eq!(sorted, str_to_letters("CAFBDE"));
+    }
+
+    #[test]
+    fn test_letter() {
+        let letter = Letter::from_char('A');
+        assert_eq!(letter.char, 'a');
+        assert_eq!(letter.seconds, 61);
+        let letter = Letter::from_char('Z');
+        assert_eq!(letter.char, 'z');
+        assert_eq!(letter.seconds, 86);
+        assert_eq!(letter.with_base(0).seconds, 26);
+
+        assert_eq!(
+            Letter {
+                char: 'c',
+                seconds: 1,
+                in_progress: 2
+            },
+            Letter {
+                char: 'c',
+                seconds: 3,
+                in_progress: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_has_map_with_letter_key() {
+        let mut hm: HashMap<Letter, Letter> = HashMap::new();
+        let a = Letter::from_char('a');
+        let b = Letter::from_char('b');
+        let mut b2 = Letter::from_char('b');
+        b2.seconds = 666;
+        assert_eq!(b2, b);
+        hm.insert(a, b);
+        assert!(hm.contains_key(&a));
+        assert_eq!(hm.remove(&a), Some(b));
+    }
+
+    // Second   Worker 1   Worker 2   Done
+    //    0        C          .
+    //    1        C          .
+    //    2        C          .
+    //    3        A          F       C
+    //    4        B          F       CA
+    //    5        B          F       CA
+    //    6        D          F       CAB
+    //    7        D          F       CAB
+    //    8        D          F       CAB
+    //    9        D          .       CABF
+    //   10        E          .       CABFD
+    //   11        E          .       CABFD
+    //   12        E          .       CABFD
+    //   13        E          .       CABFD
+    //   14        E          .       CABFD
+    //   15        .          .       CABFDE
+    //
+    #[test]
+    fn test_pipeline() {
+        // steps take 60 seconds more than in the table above
+        let mut assembly_line =
+            AssemblyLine::from_dag(dag_fixture(), 2, BASE_SECONDS).with_timeline();
+        assembly_line.process();
+
+        let table = assembly_line.to_plain();
+        let rows: Vec<&str> = table.lines().collect();
+
+        assert_eq!(rows[0], "Second   Worker 1   Worker 2   Done");
+        assert_eq!(rows[1], "   0        C          .");
+        assert_eq!(rows[64], "  63        A          F       C");
+        // header, every second and the second everything is done
+        assert_eq!(rows.len(), assembly_line.seconds + 2);
+        assert_eq!(rows.last(), Some(&" 258        .          .       CAFBDE"));
+        assert_eq!(
+            AssemblyLine::letters_to_string(assembly_line.completed),
+            "CAFBDE"
+        );
+
+        // solving alone keeps no table
+        let mut assembly_line = AssemblyLine::from_dag(dag_fixture(), 2, BASE_SECONDS);
+        assembly_line.process();
+        assert_eq!(assembly_line.seconds, 258);
+        assert!(assembly_line.timeline.is_empty());
+    }
+
+    #[test]
+    fn test_configure() {
+        let configure = |assignment: &str| {
+            let mut config = Config::new();
+            config.set(assignment).unwrap();
+            config
+                .configure::<Day7>(2018, 7)
+                .map(|day7| (day7.base_seconds, day7.workers))
+                .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(configure("2018.7.workers=26"), Ok((BASE_SECONDS, 26)));
+        assert_eq!(configure("2018.7.base_seconds=3600"), Ok((3600, WORKERS)));
+        assert_eq!(configure("2018.7.base_seconds=0"), Ok((0, WORKERS)));
+        for (assignment, error) in [
+            (
+                "2018.7.workers=0",
+                "[2018.7] workers: expected from 1 to 26, found 0",
+            ),
+            (
+                "2018.7.workers=100000000000",
+                "[2018.7] workers: expected from 1 to 26, found 100000000000",
+            ),
+            (
+                "2018.7.base_seconds=100000000",
+                "[2018.7] base_seconds: expected from 0 to 3600, found 100000000",
+            ),
+        ] {
+            assert_eq!(configure(assignment), Err(String::from(error)));
+        }
+    }
+
+    #[test]
+    fn test_example_parameters() {
+        // the table above, without the 60 seconds
+        let assembly = assemble(&dag_fixture(), 2, 0).unwrap();
+        assert_eq!(assembly.seconds, 15);
+        assert_eq!(assembly.order, "CABFDE");
+    }
+
+    #[test]
+    fn test_trace_events() {
+        let mut assembly_line = AssemblyLine::from_dag(dag_fixture(), 2, BASE_SECONDS);
+        let ((), events) = trace::capture(Level::Debug, || assembly_line.process());
+
+        assert_eq!(
+            events[0],
+            "[debug day7] step assigned second=0 worker=0 step=C"
+        );
+        assert!(events.contains(&String::from(
+            "[debug day7] step completed second=63 step=C done=C"
+        )));
+        assert_eq!(
+            events.last().unwrap(),
+            "[info day7] assembly finished seconds=258 workers=2"
+        );
+    }
+
+    #[test]
+    fn test_aoc_sort_respects_every_edge() {
+        // edges between the first ten steps, from the smaller to the larger one
+        let edges = |rng: &mut Rng, size| {
+            property::vec_of(rng, size, |rng| (rng.below(10) as u8, rng.below(10) as u8))
+        };
+        let step = |idx: u8| Letter::from_char((b'A' + idx) as char);
+
+        property::check("edges", edges, |edges: &Vec<(u8, u8)>| {
+            let mut dag = DAG::new();
+            for &(one, other) in edges.iter().filter(|(one, other)| one != other) {
+                dag.add_edge(Edge {
+                    from: step(one.min(other)),
+                    to: step(one.max(other)),
+                });
+            }
+
+            let sorted: String = dag
+                .aoc_sort()
+                .unwrap()
+                .iter()
+                .map(|letter| letter.char)
+                .collect();
+            let position = |letter: Letter| sorted.find(letter.char);
+
+            if sorted.len() != dag.graph.node_count() {
+                return Err(format!("{:?} misses steps", sorted));
+            }
+            for edge in &dag.edges {
+                if position(edge.from) > position(edge.to) {
+                    return Err(format!(
+                        "{:?} breaks {} before {}",
+                        sorted, edge.from.char, edge.to.char
+                    ));
+                }
+            }
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,190 @@
+//! Solvers of Advent of Code 2018. Every day is a module with a `DayN` type
+//! implementing `utils::Solution`: `parse` turns the puzzle input into the
+//! day's input type, `part_one` and `part_two` answer the puzzle. The steps
+//! in between are public functions of the module, to reuse them on inputs
+//! of your own.
+//!
+//! ```
+//! use aoc2018::day5::{self, Day5};
+//! use utils::Solution;
+//!
+//! let day5 = Day5::default();
+//! let polymer = day5.parse("dabAcCaCBAcCcaDA\n")?;
+//! assert_eq!(day5.part_one(&polymer)?, 10);
+//! assert_eq!(day5::find_bad_unit(&polymer, day5::ALPHABET).removed, Some('c'));
+//! # Ok::<(), utils::Error>(())
+//! ```
+//!
+//! Every entry point returns `utils::Error` instead of panicking, on input
+//! that isn't in the day's format and on puzzles without an answer.
+//!
+//! Days with parameters in their description (the size of the fabric, the
+//! number of workers, ...) are plain structs, `Default` gives the puzzle's
+//! values and `utils::Configure` reads them from an `aoc.toml`.
+//!
+//! The `aoc` runner finds the days through `registry()`, the bins of the
+//! crate run a single day.
+
+extern crate chrono;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use utils::config::CONFIG_FILE;
+use utils::{Config, ConfigError, Configure, Error, Registry, Solution, Source};
+
+pub const YEAR: u16 = 2018;
+
+/// Puzzle inputs, answers and examples of this year
+pub fn inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+}
+
+/// Where the bins look up `<day>.txt`, see `utils::input::inputs_dirs`
+pub fn inputs_dirs() -> Vec<PathBuf> {
+    utils::input::inputs_dirs(None, &inputs_dir())
+}
+
+/// Input of a day bin: the first argument is a path to the input or `-` for
+/// stdin, without one the day's input is looked up in `inputs_dirs()`. Exits
+/// with the error if the input can't be read or parsed.
+pub fn load_from_args<S: Solution>(solution: &S) -> S::Input {
+    utils::trace::init_from_env();
+
+    let source = Source::from_args(S::DAY, env::args().skip(1));
+    match solution.load(&source, &inputs_dirs()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Day of a bin with its parameters from `aoc.toml` of the working directory,
+/// exits with the error if they are invalid
+pub fn configured<S: Solution + Configure>() -> S {
+    let configured =
+        Config::load_or_default(None).and_then(|config| config.configure(YEAR, S::DAY));
+
+    configured.unwrap_or_else(|err| {
+        eprintln!("{}: {}", CONFIG_FILE, err);
+        process::exit(1);
+    })
+}
+
+/// Answer of a part for a day bin, exits with the error if there is none
+pub fn answer_or_exit<A>(answer: Result<A, Error>) -> A {
+    answer.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    })
+}
+
+/// Every day of the year with the parameters of the puzzles, for the runner
+pub fn registry() -> Registry {
+    registry_with(&Config::new()).expect("days accept their own parameters")
+}
+
+/// Every day of the year with its parameters from `config`
+pub fn registry_with(config: &Config) -> Result<Registry, ConfigError> {
+    let mut registry = Registry::new(YEAR, &inputs_dir());
+
+    registry.register(config.configure::<day1::Day1>(YEAR, day1::Day1::DAY)?);
+    registry.register(config.configure::<day2::Day2>(YEAR, day2::Day2::DAY)?);
+    registry.register(config.configure::<day3::Day3>(YEAR, day3::Day3::DAY)?);
+    registry.register(config.configure::<day4::Day4>(YEAR, day4::Day4::DAY)?);
+    registry.register(config.configure::<day5::Day5>(YEAR, day5::Day5::DAY)?);
+    registry.register(config.configure::<day6::Day6>(YEAR, day6::Day6::DAY)?);
+    registry.register(config.configure::<day7::Day7>(YEAR, day7::Day7::DAY)?);
+
+    Ok(registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+    use utils::examples::Examples;
+    use utils::fuzz::{self, Mutator};
+    use utils::ledger::Verdict;
+    use utils::Rng;
+
+    #[test]
+    fn test_all_examples() {
+        let dir = inputs_dir().join(utils::examples::EXAMPLES_DIR);
+        let examples = Examples::load(&dir).unwrap();
+        let registry = registry_with(&examples.config).unwrap();
+
+        for day in registry.days() {
+            assert!(
+                examples.for_day(day).count() > 0,
+                "no examples for day {}",
+                day
+            );
+        }
+
+        for result in examples.check_all(&registry) {
+            assert_eq!(
+                result.verdict,
+                Verdict::Pass,
+                "example {} part {} answered {}",
+                result.id,
+                result.part,
+                result.answer
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        let registry = registry();
+
+        for puzzle in registry.iter() {
+            for seed in 0..5 {
+                let input = puzzle.generate(&mut Rng::new(seed), Some(20)).unwrap();
+                let again = puzzle.generate(&mut Rng::new(seed), Some(20)).unwrap();
+                assert_eq!(input, again, "day {} seed {}", puzzle.day(), seed);
+
+                let prepared = puzzle.prepare(&input).unwrap_or_else(|errors| {
+                    panic!("day {} seed {}: {}", puzzle.day(), seed, errors)
+                });
+                // generators make puzzles with an answer for both parts
+                for (part, output) in [(1, prepared.part_one()), (2, prepared.part_two())] {
+                    if let Err(err) = output {
+                        panic!("day {} seed {} part {}: {}", puzzle.day(), seed, part, err);
+                    }
+                }
+            }
+        }
+    }
+
+    // A quick run of what `aoc fuzz` does, mutated inputs must never panic
+    #[test]
+    fn test_fuzzed_inputs() {
+        let registry = registry();
+        let mut rng = Rng::new(2018);
+
+        for puzzle in registry.iter() {
+            let corpus = (0..3)
+                .filter_map(|seed| puzzle.generate(&mut Rng::new(seed), Some(10)))
+                .collect();
+            let mutator = Mutator::new(corpus);
+
+            for _ in 0..50 {
+                let input = mutator.next(&mut rng);
+                let ran =
+                    panic::catch_unwind(AssertUnwindSafe(|| fuzz::run_target(puzzle, &input)));
+                assert!(ran.is_ok(), "day {} panicked on {:?}", puzzle.day(), input);
+            }
+        }
+    }
+}
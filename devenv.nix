@@ -24,9 +24,7 @@
   # https://devenv.sh/scripts/
   scripts.test.exec = ''
     echo "Running tests"
-    for i in $(seq 1 $AOC_DAY); do
-      cargo test --bin $i
-    done
+    cargo test
   '';
 
   scripts.run.exec = ''
@@ -50,9 +48,7 @@
   # https://devenv.sh/tests/
   enterTest = ''
     echo "Running tests"
-    for i in $(seq 1 $AOC_DAY); do
-      cargo test --bin $i
-    done
+    cargo test
   '';
 
   # https://devenv.sh/pre-commit-hooks/
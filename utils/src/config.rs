@@ -0,0 +1,465 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+//
+// Parameters of the puzzles, read from `aoc.toml`. A small subset of TOML is
+// understood: a `[year.day]` section per day holding `key = value` lines,
+// values are integers or strings in double quotes, `#` starts a comment.
+//
+//   [2018.6]
+//   max_distance = 32   # the example's region
+//
+// Days take their parameters with `Params::take`, falling back to the values
+// of the puzzle description, so an empty config runs the real puzzles.
+//
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Unreadable(PathBuf, io::Error),
+    Malformed {
+        line: usize,
+        text: String,
+    },
+    // `--set` that isn't `year.day.key=value`
+    BadOverride(String),
+    UnknownDay {
+        year: u16,
+        day: u8,
+    },
+    UnknownKey {
+        year: u16,
+        day: u8,
+        key: String,
+    },
+    Invalid {
+        year: u16,
+        day: u8,
+        key: String,
+        expected: &'static str,
+        found: Value,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Unreadable(path, err) => {
+                write!(f, "cannot read {}: {}", path.display(), err)
+            }
+            ConfigError::Malformed { line, text } => write!(
+                f,
+                "line {}: expected `[year.day]` or `key = value`, got {:?}",
+                line, text
+            ),
+            ConfigError::BadOverride(text) => {
+                write!(f, "expected `year.day.key=value`, got {:?}", text)
+            }
+            ConfigError::UnknownDay { year, day } => {
+                write!(f, "[{}.{}] is not a registered day", year, day)
+            }
+            ConfigError::UnknownKey { year, day, key } => {
+                write!(f, "[{}.{}] has no parameter {:?}", year, day, key)
+            }
+            ConfigError::Invalid {
+                year,
+                day,
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "[{}.{}] {}: expected {}, found {}",
+                year, day, key, expected, found
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Unreadable(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// 10000, -3 and 10_000 like in TOML, or "text" without escapes
+fn parse_value(text: &str) -> Option<Value> {
+    if let Some(text) = text.strip_prefix('"') {
+        let text = text.strip_suffix('"')?;
+        return match text.contains('"') {
+            true => None,
+            false => Some(Value::Str(text.to_string())),
+        };
+    }
+
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    if digits.starts_with('_') || digits.ends_with('_') {
+        return None;
+    }
+    text.replace('_', "").parse().ok().map(Value::Int)
+}
+
+// 2018.6 of a section header or an override
+fn parse_day(text: &str) -> Option<(u16, u8)> {
+    let (year, day) = text.split_once('.')?;
+
+    Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+}
+
+// Bare TOML key: letters, digits, `_` and `-`
+fn is_key(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|chr| chr.is_ascii_alphanumeric() || chr == '_' || chr == '-')
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<(u16, u8), BTreeMap<String, Value>>,
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config {
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Config, ConfigError> {
+        let mut config = Config::new();
+        let mut section = None;
+
+        for (idx, line) in text.lines().enumerate() {
+            // no `#` in strings of ours, a comment can start anywhere
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let malformed = || ConfigError::Malformed {
+                line: idx + 1,
+                text: line.to_string(),
+            };
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']').ok_or_else(malformed)?;
+                section = Some(parse_day(header).ok_or_else(malformed)?);
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(malformed)?;
+            let (key, value) = (key.trim(), value.trim());
+            let day = section.ok_or_else(malformed)?;
+            if !is_key(key) {
+                return Err(malformed());
+            }
+
+            let value = parse_value(value).ok_or_else(malformed)?;
+            config.insert(day, key, value);
+        }
+
+        Ok(config)
+    }
+
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| ConfigError::Unreadable(path.to_path_buf(), err))?;
+
+        Config::parse(&text)
+    }
+
+    // `path` if given, otherwise `aoc.toml` of the working directory if there
+    // is one, otherwise the puzzles' own parameters
+    pub fn load_or_default(path: Option<&Path>) -> Result<Config, ConfigError> {
+        match path {
+            Some(path) => Config::load(path),
+            None if Path::new(CONFIG_FILE).is_file() => Config::load(Path::new(CONFIG_FILE)),
+            None => Ok(Config::new()),
+        }
+    }
+
+    fn insert(&mut self, day: (u16, u8), key: &str, value: Value) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    // Override from the command line, `2018.7.workers=2`
+    pub fn set(&mut self, assignment: &str) -> Result<(), ConfigError> {
+        let bad = || ConfigError::BadOverride(assignment.to_string());
+
+        let (name, value) = assignment.split_once('=').ok_or_else(bad)?;
+        let (day, key) = name.trim().rsplit_once('.').ok_or_else(bad)?;
+        let day = parse_day(day).ok_or_else(bad)?;
+        let value = parse_value(value.trim()).ok_or_else(bad)?;
+        if !is_key(key) {
+            return Err(bad());
+        }
+
+        self.insert(day, key, value);
+        Ok(())
+    }
+
+    // Every day with a section, as year and day
+    pub fn days(&self) -> Vec<(u16, u8)> {
+        self.days.keys().cloned().collect()
+    }
+
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        Params {
+            year,
+            day,
+            values: self.days.get(&(year, day)).cloned().unwrap_or_default(),
+        }
+    }
+
+    // A day built from its section, keys it doesn't know are typos
+    pub fn configure<D: Configure>(&self, year: u16, day: u8) -> Result<D, ConfigError> {
+        let mut params = self.params(year, day);
+        let configured = D::configure(&mut params)?;
+
+        params.finish()?;
+        Ok(configured)
+    }
+}
+
+// Days with parameters build themselves from their section of the config
+pub trait Configure: Sized {
+    fn configure(params: &mut Params) -> Result<Self, ConfigError>;
+}
+
+// Type of a parameter and how it is written in the config
+pub trait Param: Sized {
+    const EXPECTED: &'static str;
+
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl Param for usize {
+    const EXPECTED: &'static str = "a number from 0";
+
+    fn from_value(value: &Value) -> Option<usize> {
+        match value {
+            Value::Int(value) => usize::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl Param for u32 {
+    const EXPECTED: &'static str = "a number from 0";
+
+    fn from_value(value: &Value) -> Option<u32> {
+        match value {
+            Value::Int(value) => u32::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl Param for String {
+    const EXPECTED: &'static str = "a string";
+
+    fn from_value(value: &Value) -> Option<String> {
+        match value {
+            Value::Str(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+// Parameters of a single day, taken one by one
+#[derive(Debug)]
+pub struct Params {
+    year: u16,
+    day: u8,
+    values: BTreeMap<String, Value>,
+}
+
+impl Params {
+    // Value of `key`, `default` when the config doesn't set it
+    pub fn take<T: Param>(&mut self, key: &str, default: T) -> Result<T, ConfigError> {
+        match self.values.remove(key) {
+            Some(value) => {
+                T::from_value(&value).ok_or_else(|| self.invalid(key, T::EXPECTED, value))
+            }
+            None => Ok(default),
+        }
+    }
+
+    // Error for a value the day can't use, like zero workers
+    pub fn invalid(&self, key: &str, expected: &'static str, found: Value) -> ConfigError {
+        ConfigError::Invalid {
+            year: self.year,
+            day: self.day,
+            key: key.to_string(),
+            expected,
+            found,
+        }
+    }
+
+    // Fails on the first key nothing took
+    pub fn finish(self) -> Result<(), ConfigError> {
+        match self.values.into_keys().next() {
+            Some(key) => Err(ConfigError::UnknownKey {
+                year: self.year,
+                day: self.day,
+                key,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Assembly {
+        workers: usize,
+        alphabet: String,
+    }
+
+    impl Configure for Assembly {
+        fn configure(params: &mut Params) -> Result<Assembly, ConfigError> {
+            let workers = params.take("workers", 5)?;
+            if workers == 0 {
+                return Err(params.invalid("workers", "at least 1", Value::Int(0)));
+            }
+
+            Ok(Assembly {
+                workers,
+                alphabet: params.take("alphabet", String::from("abc"))?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "# examples\n\
+             [2018.6]\n\
+             max_distance = 10_000   # of the puzzle\n\
+             \n\
+             [ 2018.7 ]\n\
+             workers=2\n\
+             alphabet = \"abcd\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.days(), vec![(2018, 6), (2018, 7)]);
+        assert_eq!(
+            config.params(2018, 6).take("max_distance", 0usize).unwrap(),
+            10_000
+        );
+        assert_eq!(
+            config.configure::<Assembly>(2018, 7).unwrap(),
+            Assembly {
+                workers: 2,
+                alphabet: String::from("abcd")
+            }
+        );
+        // days without a section keep their defaults
+        assert_eq!(
+            config.configure::<Assembly>(2018, 1).unwrap(),
+            Assembly {
+                workers: 5,
+                alphabet: String::from("abc")
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |text: &str| Config::parse(text).map(|_| ()).unwrap_err().to_string();
+
+        assert_eq!(
+            error("workers = 2\n"),
+            "line 1: expected `[year.day]` or `key = value`, got \"workers = 2\""
+        );
+        assert_eq!(
+            error("[2018.7]\nworkers = two\n"),
+            "line 2: expected `[year.day]` or `key = value`, got \"workers = two\""
+        );
+        assert_eq!(
+            error("[2018]\n"),
+            "line 1: expected `[year.day]` or `key = value`, got \"[2018]\""
+        );
+
+        let configure = |text: &str| {
+            Config::parse(text)
+                .unwrap()
+                .configure::<Assembly>(2018, 7)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            configure("[2018.7]\nworker = 2\n"),
+            "[2018.7] has no parameter \"worker\""
+        );
+        assert_eq!(
+            configure("[2018.7]\nworkers = -1\n"),
+            "[2018.7] workers: expected a number from 0, found -1"
+        );
+        assert_eq!(
+            configure("[2018.7]\nworkers = 0\n"),
+            "[2018.7] workers: expected at least 1, found 0"
+        );
+        assert_eq!(
+            configure("[2018.7]\nalphabet = 3\n"),
+            "[2018.7] alphabet: expected a string, found 3"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut config = Config::parse("[2018.7]\nworkers = 2\n").unwrap();
+
+        config.set("2018.7.workers=3").unwrap();
+        config.set("2018.7.alphabet = \"xy\"").unwrap();
+        assert_eq!(
+            config.configure::<Assembly>(2018, 7).unwrap(),
+            Assembly {
+                workers: 3,
+                alphabet: String::from("xy")
+            }
+        );
+
+        for bad in [
+            "workers=3",
+            "2018.7.workers",
+            "2018.7.=3",
+            "2018.x.workers=3",
+        ] {
+            assert_eq!(
+                config.set(bad).unwrap_err().to_string(),
+                format!("expected `year.day.key=value`, got {:?}", bad)
+            );
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::btree_map::{self, BTreeMap};
+use std::iter::FromIterator;
+
+//
+// Histogram of how many times each key was seen. Keys are kept sorted, so
+// iteration and tie breaking don't depend on hashing and answers built from a
+// counter are the same between runs.
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Ord> {
+    counts: BTreeMap<T, usize>,
+}
+
+impl<T: Ord> Default for Counter<T> {
+    fn default() -> Self {
+        Counter {
+            counts: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Ord> Counter<T> {
+    pub fn new() -> Counter<T> {
+        Counter::default()
+    }
+
+    // Returns the new count of `key`
+    pub fn add(&mut self, key: T) -> usize {
+        self.add_n(key, 1)
+    }
+
+    pub fn add_n(&mut self, key: T, n: usize) -> usize {
+        let count = self.counts.entry(key).or_insert(0);
+        *count += n;
+        *count
+    }
+
+    // Zero for keys never seen
+    pub fn get(&self, key: &T) -> usize {
+        self.counts.get(key).cloned().unwrap_or(0)
+    }
+
+    // Number of distinct keys
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    // Sum of all counts
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    // Keys with their counts, in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(key, count)| (key, *count))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &T> {
+        self.counts.keys()
+    }
+
+    // Adds the counts of `other` to this counter
+    pub fn merge(&mut self, other: Counter<T>) {
+        for (key, count) in other.counts {
+            self.add_n(key, count);
+        }
+    }
+
+    // Highest count, zero for an empty counter
+    pub fn max_count(&self) -> usize {
+        self.counts.values().cloned().max().unwrap_or(0)
+    }
+
+    // Every key sharing the highest count, in key order. Callers wanting a
+    // single key take the first one, the smallest.
+    pub fn argmax(&self) -> Vec<&T> {
+        let max = self.max_count();
+
+        self.counts
+            .iter()
+            .filter(|(_, count)| **count == max)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    // Up to `n` keys by descending count, ties in key order
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut common: Vec<_> = self.iter().collect();
+        // stable sort keeps the key order of equal counts
+        common.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        common.truncate(n);
+        common
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Counter<T> {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Ord> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<T: Ord> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = btree_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let mut counter: Counter<char> = "bababc".chars().collect();
+
+        assert_eq!(counter.get(&'a'), 2);
+        assert_eq!(counter.get(&'b'), 3);
+        assert_eq!(counter.get(&'z'), 0);
+        assert_eq!((counter.len(), counter.total()), (3, 6));
+        assert_eq!(counter.add('c'), 2);
+        assert_eq!(
+            counter.iter().collect::<Vec<_>>(),
+            vec![(&'a', 2), (&'b', 3), (&'c', 2)]
+        );
+    }
+
+    #[test]
+    fn test_ties_are_ordered() {
+        let counter: Counter<u32> = vec![40, 24, 7, 24, 40, 3].into_iter().collect();
+
+        assert_eq!(counter.argmax(), vec![&24, &40]);
+        assert_eq!(counter.max_count(), 2);
+        assert_eq!(counter.most_common(3), vec![(&24, 2), (&40, 2), (&3, 1)]);
+        assert!(Counter::<u32>::new().argmax().is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut one: Counter<&str> = vec!["a", "b"].into_iter().collect();
+        let two: Counter<&str> = vec!["b", "c", "c"].into_iter().collect();
+
+        one.merge(two);
+        assert_eq!(
+            one.into_iter().collect::<Vec<_>>(),
+            vec![("a", 1), ("b", 2), ("c", 2)]
+        );
+    }
+}
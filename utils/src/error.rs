@@ -0,0 +1,84 @@
+use crate::input::InputError;
+use crate::parse::{ParseError, ParseErrors};
+use std::error;
+use std::fmt;
+
+//
+// Everything that keeps a day from answering, returned by every entry point
+// of `Solution`: an input that can't be read, one that isn't in the day's
+// format, or one that parses but makes a puzzle without an answer (no
+// frequency repeats, no guard ever falls asleep, ...). Days return errors
+// instead of panicking, the runner reports them and exits with 1.
+//
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(ParseErrors),
+    // why the parsed input has no answer, e.g. "every claim overlaps another"
+    NoAnswer(String),
+}
+
+impl Error {
+    pub fn no_answer(reason: &str) -> Error {
+        Error::NoAnswer(String::from(reason))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Input(err) => write!(f, "{}", err),
+            Error::Parse(errors) => write!(f, "invalid input: {}", errors),
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Input(err) => Some(err),
+            Error::Parse(errors) => Some(errors),
+            Error::NoAnswer(_) => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Error {
+        Error::Input(err)
+    }
+}
+
+impl From<ParseErrors> for Error {
+    fn from(errors: ParseErrors) -> Error {
+        Error::Parse(errors)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::Parse(ParseErrors::from(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+
+    #[test]
+    fn test_display() {
+        let err = Error::from(ParseError::input("a coordinate", None));
+        assert_eq!(
+            err.to_string(),
+            "invalid input: input: expected a coordinate, found end of input"
+        );
+
+        let err = Error::from(InputError::Empty(Source::Stdin));
+        assert_eq!(err.to_string(), "<stdin> is empty");
+
+        let err = Error::no_answer("the frequency never repeats");
+        assert_eq!(err.to_string(), "no answer: the frequency never repeats");
+    }
+}
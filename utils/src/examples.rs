@@ -0,0 +1,202 @@
+use crate::config::{Config, ConfigError, CONFIG_FILE};
+use crate::input::{self, InputError};
+use crate::ledger::{Ledger, LedgerError, Verdict};
+use crate::registry::Registry;
+use crate::solution::Puzzle;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+//
+// Worked examples from the puzzle descriptions live in `inputs/examples` as
+// `<day>-<n>.txt`, their expected answers in `inputs/examples/answers.txt`
+// (see `Ledger`) keyed by the file name without extension. Examples with
+// smaller parameters than the real puzzle set them in
+// `inputs/examples/aoc.toml` (see `Config`).
+//
+pub const EXAMPLES_DIR: &str = "examples";
+
+#[derive(Debug)]
+pub enum ExamplesError {
+    NotFound(Vec<PathBuf>),
+    Input(InputError),
+    Answers(LedgerError),
+    Config(ConfigError),
+}
+
+impl fmt::Display for ExamplesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExamplesError::NotFound(searched) => {
+                let searched = searched
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "examples not found, searched: {}", searched)
+            }
+            ExamplesError::Input(err) => write!(f, "{}", err),
+            ExamplesError::Answers(err) => write!(f, "examples answers: {}", err),
+            ExamplesError::Config(err) => write!(f, "examples {}: {}", CONFIG_FILE, err),
+        }
+    }
+}
+
+impl Error for ExamplesError {}
+
+#[derive(Debug)]
+pub struct Example {
+    pub id: String,
+    pub day: u8,
+    pub input: String,
+}
+
+pub struct ExampleResult {
+    pub id: String,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+pub struct Examples {
+    pub examples: Vec<Example>,
+    pub answers: Ledger,
+    // parameters of the puzzles in the examples, empty without an `aoc.toml`
+    pub config: Config,
+}
+
+// `dirs` are the inputs directories of a year, see `Registry::inputs_dirs`
+pub fn find_examples_dir(dirs: &[PathBuf]) -> Result<PathBuf, ExamplesError> {
+    let searched: Vec<PathBuf> = dirs.iter().map(|dir| dir.join(EXAMPLES_DIR)).collect();
+
+    match searched.iter().find(|dir| dir.is_dir()) {
+        Some(dir) => Ok(dir.clone()),
+        None => Err(ExamplesError::NotFound(searched)),
+    }
+}
+
+// "5-1" => Some(5)
+fn example_day(id: &str) -> Option<u8> {
+    let (day, n) = id.split_once('-')?;
+    n.parse::<u32>().ok()?;
+    day.parse().ok()
+}
+
+impl Examples {
+    pub fn load(dir: &Path) -> Result<Examples, ExamplesError> {
+        let answers = Ledger::load(&dir.join("answers.txt")).map_err(ExamplesError::Answers)?;
+        let config = match dir.join(CONFIG_FILE) {
+            path if path.is_file() => Config::load(&path).map_err(ExamplesError::Config)?,
+            _ => Config::new(),
+        };
+        let entries = fs::read_dir(dir).map_err(|err| {
+            ExamplesError::Input(InputError::Unreadable(
+                input::Source::Path(dir.to_path_buf()),
+                err,
+            ))
+        })?;
+
+        let mut examples = vec![];
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let id = match path.file_stem().and_then(|stem| stem.to_str()) {
+                Some(id) if path.extension().is_some_and(|ext| ext == "txt") => id,
+                _ => continue,
+            };
+
+            if let Some(day) = example_day(id) {
+                examples.push(Example {
+                    id: id.to_string(),
+                    day,
+                    input: input::read_input_file(&path).map_err(ExamplesError::Input)?,
+                });
+            }
+        }
+
+        // 1-2 before 1-10
+        examples.sort_by_key(|example| {
+            let (_, n) = example.id.split_once('-').unwrap_or_default();
+            (example.day, n.parse::<u32>().unwrap_or_default())
+        });
+
+        Ok(Examples {
+            examples,
+            answers,
+            config,
+        })
+    }
+
+    pub fn for_day(&self, day: u8) -> impl Iterator<Item = &Example> {
+        self.examples
+            .iter()
+            .filter(move |example| example.day == day)
+    }
+
+    // Solves the parts `answers.txt` has an expected value for
+    pub fn check(&self, puzzle: &dyn Puzzle, example: &Example) -> Vec<ExampleResult> {
+        let parts: Vec<u8> = [1, 2]
+            .iter()
+            .cloned()
+            .filter(|part| self.answers.expected(&example.id, *part).is_some())
+            .collect();
+
+        if parts.is_empty() {
+            return vec![];
+        }
+
+        let prepared = puzzle.prepare(&example.input);
+
+        parts
+            .into_iter()
+            .map(|part| {
+                // errors are answers too, a wrong one unless expected
+                let answer = match &prepared {
+                    Ok(prepared) => {
+                        let output = match part {
+                            1 => prepared.part_one(),
+                            _ => prepared.part_two(),
+                        };
+                        output.map_or_else(|err| err.to_string(), |output| output.answer)
+                    }
+                    Err(err) => err.to_string(),
+                };
+
+                ExampleResult {
+                    id: example.id.clone(),
+                    day: example.day,
+                    part,
+                    verdict: self.answers.check(&example.id, part, &answer),
+                    answer,
+                }
+            })
+            .collect()
+    }
+
+    pub fn check_all(&self, registry: &Registry) -> Vec<ExampleResult> {
+        self.examples
+            .iter()
+            .filter_map(|example| {
+                registry
+                    .get(example.day)
+                    .map(|puzzle| self.check(puzzle, example))
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_day() {
+        assert_eq!(example_day("5-1"), Some(5));
+        assert_eq!(example_day("12-10"), Some(12));
+        assert_eq!(example_day("answers"), None);
+        assert_eq!(example_day("5-x"), None);
+    }
+}
@@ -0,0 +1,350 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const USER_AGENT: &str = "github.com/antono/aoc2018 by antono.vasiljev@gmail.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    Status { url: String, status: u16 },
+    Transport(String),
+    Cache(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "no session token, set ${}", SESSION_ENV),
+            FetchError::Status { url, status } => write!(f, "GET {} returned {}", url, status),
+            FetchError::Transport(message) => write!(f, "request failed: {}", message),
+            FetchError::Cache(path, err) => write!(f, "cannot write {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+// Performs a single authenticated GET, returning the response body
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+//
+// Talks HTTPS through the `curl` binary, so we don't need a TLS stack. The
+// session cookie is passed on stdin to keep it out of the process list.
+//
+pub struct CurlTransport;
+
+impl Transport for CurlTransport {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--location"])
+            .args(["--user-agent", USER_AGENT])
+            .args(["--header", "@-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| FetchError::Transport(format!("cannot run curl: {}", err)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", session)
+                .map_err(|err| FetchError::Transport(err.to_string()))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|err| FetchError::Transport(err.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(FetchError::Transport(stderr.trim().to_string()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| FetchError::Transport(String::from("no status from curl")))?;
+        let status: u16 = status
+            .trim()
+            .parse()
+            .map_err(|_| FetchError::Transport(format!("bad status from curl: {}", status)))?;
+
+        if status != 200 {
+            return Err(FetchError::Status {
+                url: url.to_string(),
+                status,
+            });
+        }
+
+        Ok(body.to_string())
+    }
+}
+
+//
+// Bare HTTP/1.0 client for plain `http://` urls. Good enough for local stub
+// servers in tests, adventofcode.com itself needs `CurlTransport`.
+//
+pub struct HttpTransport;
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let transport_err = |err: io::Error| FetchError::Transport(err.to_string());
+
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| FetchError::Transport(format!("not an http:// url: {}", url)))?;
+        let (host, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, "/"),
+        };
+
+        let mut stream = TcpStream::connect(host).map_err(transport_err)?;
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n\r\n",
+            path, host, USER_AGENT, session
+        )
+        .map_err(transport_err)?;
+
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .map_err(transport_err)?;
+
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| FetchError::Transport(String::from("malformed response")))?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or_else(|| FetchError::Transport(String::from("malformed status line")))?;
+
+        if status != 200 {
+            return Err(FetchError::Status {
+                url: url.to_string(),
+                status,
+            });
+        }
+
+        Ok(body.to_string())
+    }
+}
+
+// Keeps at least `min_interval` between consecutive requests
+pub struct Throttle {
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Throttle {
+        Throttle {
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    pub fn wait(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+pub fn session_from_env() -> Result<String, FetchError> {
+    match env::var(SESSION_ENV) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(FetchError::NoSession),
+    }
+}
+
+//
+// Downloads puzzle inputs of `year` into `cache_dir/<day>.txt`. A cached file is never
+// downloaded again, so it is safe to call `fetch` for every day on each run.
+//
+pub struct Fetcher<T: Transport> {
+    transport: T,
+    session: String,
+    base_url: String,
+    year: u16,
+    cache_dir: PathBuf,
+    throttle: Throttle,
+}
+
+impl<T: Transport> Fetcher<T> {
+    pub fn new(transport: T, session: &str, year: u16, cache_dir: &Path) -> Fetcher<T> {
+        Fetcher {
+            transport,
+            session: session.to_string(),
+            base_url: BASE_URL.to_string(),
+            year,
+            cache_dir: cache_dir.to_path_buf(),
+            throttle: Throttle::new(Duration::from_secs(3)),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.throttle = Throttle::new(min_interval);
+        self
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("{}.txt", day))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, self.year, day)
+    }
+
+    // Returns path of the cached input and whether it was downloaded just now
+    pub fn fetch(&mut self, day: u8) -> Result<(PathBuf, bool), FetchError> {
+        let path = self.cached_path(day);
+
+        if path.is_file() {
+            return Ok((path, false));
+        }
+
+        self.throttle.wait();
+        let body = self.transport.get(&self.url(day), &self.session)?;
+
+        if body.trim().is_empty() {
+            return Err(FetchError::Transport(format!(
+                "empty input received for day {}",
+                day
+            )));
+        }
+
+        // write to a temporary file first, so an interrupted download is never cached
+        let partial = path.with_extension("txt.part");
+        let cache_err = |err| FetchError::Cache(path.clone(), err);
+
+        fs::create_dir_all(&self.cache_dir).map_err(cache_err)?;
+        fs::write(&partial, body).map_err(cache_err)?;
+        fs::rename(&partial, &path).map_err(cache_err)?;
+
+        Ok((path, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    // Serves `responses` in order, reporting each request line and cookie
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![];
+
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    request.push(line);
+                }
+
+                let cookie = request
+                    .iter()
+                    .find(|line| line.starts_with("Cookie:"))
+                    .cloned()
+                    .unwrap_or_default();
+                sender.send(format!("{} | {}", request[0], cookie)).unwrap();
+
+                write!(stream, "HTTP/1.0 {} Stub\r\n\r\n{}", status, body).unwrap();
+            }
+        });
+
+        (format!("http://{}", address), receiver)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let (url, requests) = stub_server(vec![(200, "+1\n-2\n")]);
+        let dir = cache_dir("cache");
+        let mut fetcher = Fetcher::new(HttpTransport, "s3cr3t", 2018, &dir)
+            .with_base_url(&url)
+            .with_min_interval(Duration::from_millis(0));
+
+        let (path, downloaded) = fetcher.fetch(1).unwrap();
+        assert!(downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "+1\n-2\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2018/day/1/input HTTP/1.0 | Cookie: session=s3cr3t"
+        );
+
+        // the stub is gone now, so this would fail if it hit the network
+        let (cached, downloaded) = fetcher.fetch(1).unwrap();
+        assert!(!downloaded);
+        assert_eq!(cached, path);
+    }
+
+    #[test]
+    fn test_fetch_errors_are_not_cached() {
+        let (url, _requests) = stub_server(vec![(404, "Not Found"), (200, "  \n")]);
+        let dir = cache_dir("errors");
+        let mut fetcher = Fetcher::new(HttpTransport, "s3cr3t", 2018, &dir)
+            .with_base_url(&url)
+            .with_min_interval(Duration::from_millis(0));
+
+        match fetcher.fetch(26) {
+            Err(FetchError::Status { status, .. }) => assert_eq!(status, 404),
+            other => panic!("expected 404, got {:?}", other),
+        }
+        assert!(fetcher.fetch(2).is_err());
+
+        assert!(!fetcher.cached_path(26).exists());
+        assert!(!fetcher.cached_path(2).exists());
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut throttle = Throttle::new(Duration::from_millis(50));
+        let started = Instant::now();
+
+        throttle.wait();
+        throttle.wait();
+        throttle.wait();
+
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+}
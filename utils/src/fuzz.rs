@@ -0,0 +1,267 @@
+use crate::random::Rng;
+use crate::render::{Canvas, Style};
+use crate::solution::Puzzle;
+
+//
+// Mutation fuzzing of the days: valid inputs from a corpus are changed a
+// little at a time, characters flipped, lines dropped, numbers replaced by
+// huge ones, and every day entry point is run on the result. Inputs that
+// don't parse are fine, a day must only never panic or hang on them. A
+// failing input is minimized before it is reported.
+//
+//   let mutator = Mutator::new(corpus);
+//   let input = mutator.next(&mut rng);
+//   run_target(puzzle, &input);
+//
+
+// Tokens that tend to upset parsers: numbers past integer limits, the
+// punctuation of the puzzle formats and a character that is not ASCII
+pub const TOKENS: [&str; 15] = [
+    "0",
+    "-1",
+    "99999999999999999999",
+    "4294967296",
+    "#",
+    "@",
+    ",",
+    ":",
+    "x",
+    "[",
+    "]",
+    "Step",
+    "é",
+    "\n",
+    " ",
+];
+
+// Numbers replacing numbers of the input, the limits of common integer types
+const NUMBERS: [&str; 8] = [
+    "0",
+    "-1",
+    "1000",
+    "1001",
+    "2147483648",
+    "4294967296",
+    "-9223372036854775808",
+    "99999999999999999999",
+];
+
+// Give up on making a failing input smaller after this many tries
+const MAX_ATTEMPTS: usize = 2000;
+
+pub struct Mutator {
+    corpus: Vec<String>,
+}
+
+impl Mutator {
+    pub fn new(corpus: Vec<String>) -> Mutator {
+        Mutator { corpus }
+    }
+
+    pub fn corpus(&self) -> &[String] {
+        &self.corpus
+    }
+
+    // Entry of the corpus with one to four mutations
+    pub fn next(&self, rng: &mut Rng) -> String {
+        let mut input = rng.choose(&self.corpus).cloned().unwrap_or_default();
+
+        for _ in 0..rng.between(1, 4) {
+            input = self.mutate(rng, &input);
+        }
+
+        input
+    }
+
+    // Single random change, made on characters so the input stays valid UTF-8
+    pub fn mutate(&self, rng: &mut Rng, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        let mut lines: Vec<String> = input.split_inclusive('\n').map(String::from).collect();
+
+        match rng.below(8) {
+            0 if !chars.is_empty() => {
+                let idx = rng.below(chars.len());
+                chars[idx] = random_char(rng);
+            }
+            1 if !chars.is_empty() => {
+                let start = rng.below(chars.len());
+                let end = (start + rng.between(1, 8) as usize).min(chars.len());
+                chars.drain(start..end);
+            }
+            2 => {
+                let idx = rng.below(chars.len() + 1);
+                let token = rng.choose(&TOKENS).unwrap();
+                chars.splice(idx..idx, token.chars());
+            }
+            3 => return replace_number(rng, &chars),
+            4 if !lines.is_empty() => {
+                let line = rng.choose(&lines).unwrap().clone();
+                lines.insert(rng.below(lines.len() + 1), line);
+                return lines.concat();
+            }
+            5 if !lines.is_empty() => {
+                let idx = rng.below(lines.len());
+                if rng.chance(0.5) {
+                    lines.remove(idx);
+                } else {
+                    let other = rng.below(lines.len());
+                    lines.swap(idx, other);
+                }
+                return lines.concat();
+            }
+            6 => {
+                // head of this input, tail of another one
+                let other = rng.choose(&self.corpus).cloned().unwrap_or_default();
+                let other: Vec<&str> = other.split_inclusive('\n').collect();
+                let head = rng.below(lines.len() + 1);
+                let tail = rng.below(other.len() + 1);
+                return lines[..head].concat() + &other[tail..].concat();
+            }
+            _ => {
+                let idx = rng.below(chars.len() + 1);
+                chars.insert(idx, random_char(rng));
+            }
+        }
+
+        chars.into_iter().collect()
+    }
+}
+
+// Mostly printable ASCII, sometimes whitespace, a control or a wide character
+fn random_char(rng: &mut Rng) -> char {
+    if rng.chance(0.1) {
+        *rng.choose(&['\n', '\t', '\r', '\0', 'é', '€', '\u{1f384}'])
+            .unwrap()
+    } else {
+        (b' ' + rng.below(95) as u8) as char
+    }
+}
+
+// One run of digits replaced by a number at some limit, if there is a number
+fn replace_number(rng: &mut Rng, chars: &[char]) -> String {
+    let mut numbers = vec![];
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let start = idx;
+        while idx < chars.len() && chars[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        if idx > start {
+            numbers.push((start, idx));
+        }
+        idx += 1;
+    }
+
+    let mut chars = chars.to_vec();
+    if let Some(&(start, end)) = rng.choose(&numbers) {
+        let number = match rng.choose(&NUMBERS) {
+            Some(number) if rng.chance(0.7) => number.to_string(),
+            _ => rng.between(-100, 2000).to_string(),
+        };
+        chars.splice(start..end, number.chars());
+    }
+
+    chars.into_iter().collect()
+}
+
+//
+// Smaller input for which `fails` still holds: runs of lines are removed
+// first, then runs of characters, from half the input down to single ones.
+// `input` itself is expected to fail.
+//
+pub fn minimize<F: FnMut(&str) -> bool>(input: &str, mut fails: F) -> String {
+    let mut attempts = 0;
+    let mut fails = |candidate: &str| {
+        attempts += 1;
+        attempts <= MAX_ATTEMPTS && fails(candidate)
+    };
+
+    let lines: Vec<String> = input.split_inclusive('\n').map(String::from).collect();
+    let lines = remove_runs(lines, &mut fails);
+
+    let chars: Vec<String> = lines.concat().chars().map(String::from).collect();
+    remove_runs(chars, &mut fails).concat()
+}
+
+fn remove_runs<F: FnMut(&str) -> bool>(mut units: Vec<String>, fails: &mut F) -> Vec<String> {
+    let mut run = (units.len() / 2).max(1);
+
+    loop {
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + run).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+
+            if fails(&candidate.concat()) {
+                units = candidate;
+            } else {
+                start += run;
+            }
+        }
+
+        if run == 1 {
+            return units;
+        }
+        run /= 2;
+    }
+}
+
+//
+// Every entry point of a day on `input`, true when the input parsed. Invalid
+// input is fine, panics are not.
+//
+pub fn run_target(puzzle: &dyn Puzzle, input: &str) -> bool {
+    match puzzle.prepare(input) {
+        Ok(prepared) => {
+            // parts may have no answer, that's an error like any other
+            let _ = prepared.part_one();
+            let _ = prepared.part_two();
+            prepared.visualize(&mut Canvas::new(Style::Plain).with_limits(120, 60));
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutations() {
+        let mutator = Mutator::new(vec![String::from("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n")]);
+        let mut rng = Rng::new(2018);
+
+        let inputs: Vec<String> = (0..200).map(|_| mutator.next(&mut rng)).collect();
+        let again: Vec<String> = {
+            let mut rng = Rng::new(2018);
+            (0..200).map(|_| mutator.next(&mut rng)).collect()
+        };
+
+        assert_eq!(inputs, again);
+        assert!(inputs.iter().any(|input| input != &mutator.corpus()[0]));
+        assert!(inputs
+            .iter()
+            .any(|input| input.contains("99999999999999999999")));
+        assert!(inputs.iter().any(|input| input.lines().count() == 1));
+
+        // an empty input can still grow
+        let empty = Mutator::new(vec![String::new()]);
+        assert!((0..50).any(|_| !empty.next(&mut rng).is_empty()));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2\n";
+
+        assert_eq!(minimize(input, |candidate| candidate.contains('5')), "5");
+        assert_eq!(minimize(input, |candidate| candidate.contains("#2")), "#2");
+        // a failure that needs two lines keeps both
+        assert_eq!(
+            minimize(input, |candidate| candidate.matches('\n').count() >= 2),
+            "\n\n"
+        );
+        assert_eq!(minimize(input, |_| false), input);
+    }
+}
@@ -0,0 +1,322 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+//
+// Integer types usable as coordinates. Differences are taken as the larger
+// minus the smaller value, so unsigned coordinates work too.
+//
+pub trait Number:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+number!(i32, i64, isize, u32, u64, usize);
+
+fn max<T: Number>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+fn min<T: Number>(a: T, b: T) -> T {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Number> Point2<T> {
+    pub fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    // Also known as taxicab or rectilinear distance
+    pub fn manhattan(&self, other: &Point2<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // Number of king moves on a chess board
+    pub fn chebyshev(&self, other: &Point2<T>) -> T {
+        max(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+    }
+
+    pub fn euclidean(&self, other: &Point2<T>) -> f64 {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+
+        (dx * dx + dy * dy).as_f64().sqrt()
+    }
+}
+
+impl<T: Number> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Number> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Number> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3<T>) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Point3<T>) -> T {
+        max(
+            max(self.x.abs_diff(other.x), self.y.abs_diff(other.y)),
+            self.z.abs_diff(other.z),
+        )
+    }
+
+    pub fn euclidean(&self, other: &Point3<T>) -> f64 {
+        let (dx, dy, dz) = (
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        );
+
+        (dx * dx + dy * dy + dz * dz).as_f64().sqrt()
+    }
+}
+
+impl<T: Number> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Number> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+//
+// Axis aligned rectangle of whole cells: `min` is the top left cell, `max` is
+// just past the bottom right one, so a 2x3 rectangle at 1,1 has max 3,4.
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Number> Rect<T> {
+    pub fn new(min: Point2<T>, max: Point2<T>) -> Rect<T> {
+        Rect { min, max }
+    }
+
+    pub fn from_size(origin: Point2<T>, width: T, height: T) -> Rect<T> {
+        Rect::new(origin, Point2::new(origin.x + width, origin.y + height))
+    }
+
+    // Smallest rectangle covering every point, None when there are none
+    pub fn bounding_box<I: IntoIterator<Item = Point2<T>>>(points: I) -> Option<Rect<T>> {
+        points.into_iter().fold(None, |bounds, point| {
+            let cell = Rect::from_size(point, T::ONE, T::ONE);
+
+            Some(match bounds {
+                Some(bounds) => bounds.union(&cell),
+                None => cell,
+            })
+        })
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x.abs_diff(self.min.x)
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y.abs_diff(self.min.y)
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max.x <= self.min.x || self.max.y <= self.min.y
+    }
+
+    pub fn contains(&self, point: &Point2<T>) -> bool {
+        self.min.x <= point.x
+            && point.x < self.max.x
+            && self.min.y <= point.y
+            && point.y < self.max.y
+    }
+
+    // Cells covered by both rectangles
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let rect = Rect::new(
+            Point2::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
+            Point2::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+        );
+
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    // Smallest rectangle covering both
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        Rect::new(
+            Point2::new(min(self.min.x, other.min.x), min(self.min.y, other.min.y)),
+            Point2::new(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
+        )
+    }
+
+    // Every cell, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2<T>> {
+        let rect = *self;
+        let mut next = Point2::new(rect.min.x, rect.min.y);
+
+        std::iter::from_fn(move || {
+            if rect.is_empty() || next.y >= rect.max.y {
+                return None;
+            }
+
+            let point = next;
+            next.x = next.x + T::ONE;
+            if next.x >= rect.max.x {
+                next = Point2::new(rect.min.x, next.y + T::ONE);
+            }
+
+            Some(point)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        let a = Point2::new(1, 1);
+        let b = Point2::new(4, 5);
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.euclidean(&b), 5.0);
+        assert_eq!(Point2::new(5u32, 1).manhattan(&Point2::new(1, 6)), 9);
+
+        let c = Point3::new(0i64, 0, 0);
+        let d = Point3::new(-1, 2, -2);
+        assert_eq!(c.manhattan(&d), 5);
+        assert_eq!(c.chebyshev(&d), 2);
+        assert_eq!(c.euclidean(&d), 3.0);
+        assert_eq!(d - c + d, Point3::new(-2, 4, -4));
+    }
+
+    #[test]
+    fn test_rect() {
+        // claims #1 @ 1,3: 4x4 and #2 @ 3,1: 4x4 from day 3
+        let one = Rect::from_size(Point2::new(1, 3), 4, 4);
+        let two = Rect::from_size(Point2::new(3, 1), 4, 4);
+        let three = Rect::from_size(Point2::new(5, 5), 2, 2);
+
+        assert_eq!(one.area(), 16);
+        assert_eq!(
+            one.intersection(&two),
+            Some(Rect::from_size(Point2::new(3, 3), 2, 2))
+        );
+        assert_eq!(one.intersection(&three), None);
+        assert_eq!(
+            one.union(&two),
+            Rect::new(Point2::new(1, 1), Point2::new(7, 7))
+        );
+        assert!(one.contains(&Point2::new(4, 6)));
+        assert!(!one.contains(&Point2::new(5, 6)));
+        assert_eq!(
+            three.points().collect::<Vec<_>>(),
+            vec![
+                Point2::new(5, 5),
+                Point2::new(6, 5),
+                Point2::new(5, 6),
+                Point2::new(6, 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = vec![Point2::new(1, 1), Point2::new(8, 3), Point2::new(-2, 9)];
+        let bounds = Rect::bounding_box(points.clone()).unwrap();
+
+        assert_eq!(bounds, Rect::new(Point2::new(-2, 1), Point2::new(9, 10)));
+        assert!(points.iter().all(|point| bounds.contains(point)));
+        assert_eq!(Rect::<i32>::bounding_box(vec![]), None);
+    }
+}
@@ -0,0 +1,377 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+
+//
+// Directed graph with weighted edges, adjacency is kept in both directions so
+// predecessors and in-degrees are as cheap as successors. Nodes are stored in
+// order, every walk visits neighbors smallest first and its result doesn't
+// change between runs.
+//
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph<N: Ord> {
+    successors: BTreeMap<N, BTreeMap<N, u64>>,
+    predecessors: BTreeMap<N, BTreeSet<N>>,
+}
+
+impl<N: Ord + Clone> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            successors: BTreeMap::new(),
+            predecessors: BTreeMap::new(),
+        }
+    }
+}
+
+impl<N: Ord + Clone> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph::default()
+    }
+
+    // Returns false if the node was already there
+    pub fn add_node(&mut self, node: N) -> bool {
+        if self.contains(&node) {
+            return false;
+        }
+
+        self.predecessors.insert(node.clone(), BTreeSet::new());
+        self.successors.insert(node, BTreeMap::new());
+        true
+    }
+
+    // Edge of weight 1, missing nodes are added
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    // Adding an edge twice only updates its weight
+    pub fn add_weighted_edge(&mut self, from: N, to: N, weight: u64) {
+        self.add_node(from.clone());
+        self.add_node(to.clone());
+
+        self.predecessors.get_mut(&to).unwrap().insert(from.clone());
+        self.successors.get_mut(&from).unwrap().insert(to, weight);
+    }
+
+    // Removes the node with all of its edges, returns false if it wasn't there
+    pub fn remove_node(&mut self, node: &N) -> bool {
+        let successors = match self.successors.remove(node) {
+            Some(successors) => successors,
+            None => return false,
+        };
+        let predecessors = self.predecessors.remove(node).unwrap_or_default();
+
+        for next in successors.keys() {
+            if let Some(incoming) = self.predecessors.get_mut(next) {
+                incoming.remove(node);
+            }
+        }
+        for prev in &predecessors {
+            if let Some(outgoing) = self.successors.get_mut(prev) {
+                outgoing.remove(node);
+            }
+        }
+        true
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.successors.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.successors.keys()
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.successors.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.successors.values().map(|edges| edges.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    // Edges as `(from, to, weight)`
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, u64)> {
+        self.successors
+            .iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |(to, weight)| (from, to, *weight)))
+    }
+
+    pub fn weight(&self, from: &N, to: &N) -> Option<u64> {
+        self.successors
+            .get(from)
+            .and_then(|edges| edges.get(to))
+            .cloned()
+    }
+
+    // Nodes `node` has an edge to, empty for unknown nodes
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.successors
+            .get(node)
+            .into_iter()
+            .flat_map(|edges| edges.keys())
+    }
+
+    // Nodes with an edge to `node`
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.predecessors.get(node).into_iter().flatten()
+    }
+
+    pub fn in_degree(&self, node: &N) -> usize {
+        self.predecessors
+            .get(node)
+            .map_or(0, |incoming| incoming.len())
+    }
+
+    pub fn out_degree(&self, node: &N) -> usize {
+        self.successors
+            .get(node)
+            .map_or(0, |outgoing| outgoing.len())
+    }
+
+    // Nodes without incoming edges
+    pub fn roots(&self) -> Vec<&N> {
+        self.predecessors
+            .iter()
+            .filter(|(_, incoming)| incoming.is_empty())
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    //
+    // Kahn's algorithm, always taking the smallest node that has no remaining
+    // incoming edges. None when the graph has a cycle.
+    //
+    pub fn topological_sort(&self) -> Option<Vec<N>> {
+        let mut in_degrees: BTreeMap<&N, usize> = self
+            .predecessors
+            .iter()
+            .map(|(node, incoming)| (node, incoming.len()))
+            .collect();
+        let mut ready: BTreeSet<&N> = self.roots().into_iter().collect();
+        let mut sorted = Vec::with_capacity(self.node_count());
+
+        while let Some(node) = ready.pop_first() {
+            sorted.push(node.clone());
+
+            for next in self.successors(node) {
+                let in_degree = in_degrees.get_mut(next).unwrap();
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    ready.insert(next);
+                }
+            }
+        }
+
+        if sorted.len() == self.node_count() {
+            Some(sorted)
+        } else {
+            None
+        }
+    }
+
+    // Nodes in breadth first order from `start`, including it
+    pub fn bfs(&self, start: &N) -> Vec<N> {
+        let mut seen = BTreeSet::new();
+        let mut queue = VecDeque::new();
+        let mut order = vec![];
+
+        if self.contains(start) {
+            seen.insert(start);
+            queue.push_back(start);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node.clone());
+
+            for next in self.successors(node) {
+                if seen.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        order
+    }
+
+    // Nodes in depth first preorder from `start`, including it
+    pub fn dfs(&self, start: &N) -> Vec<N> {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![];
+        let mut order = vec![];
+
+        if self.contains(start) {
+            stack.push(start);
+        }
+
+        while let Some(node) = stack.pop() {
+            if !seen.insert(node) {
+                continue;
+            }
+            order.push(node.clone());
+
+            // reversed so the smallest successor is visited first
+            let successors: Vec<&N> = self.successors(node).collect();
+            for next in successors.into_iter().rev() {
+                if !seen.contains(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        order
+    }
+
+    // Every node reachable from `start`, including it
+    pub fn reachable(&self, start: &N) -> BTreeSet<N> {
+        self.bfs(start).into_iter().collect()
+    }
+
+    pub fn is_reachable(&self, from: &N, to: &N) -> bool {
+        self.reachable(from).contains(to)
+    }
+
+    // Length of the shortest path from `start` to every node reachable from it
+    pub fn dijkstra(&self, start: &N) -> BTreeMap<N, u64> {
+        self.shortest_paths(start)
+            .into_iter()
+            .map(|(node, (distance, _))| (node.clone(), distance))
+            .collect()
+    }
+
+    // Shortest path from `from` to `to`, both included, with its length
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(u64, Vec<N>)> {
+        let paths = self.shortest_paths(from);
+        let (distance, _) = *paths.get(to)?;
+
+        let mut path = vec![to.clone()];
+        let mut node = to;
+        while let Some((_, Some(prev))) = paths.get(node) {
+            path.push((*prev).clone());
+            node = prev;
+        }
+        path.reverse();
+
+        Some((distance, path))
+    }
+
+    // Distance and previous node on the shortest path for every reached node
+    fn shortest_paths<'a>(&'a self, start: &'a N) -> BTreeMap<&'a N, (u64, Option<&'a N>)> {
+        let mut paths = BTreeMap::new();
+        let mut queue = BinaryHeap::new();
+
+        if self.contains(start) {
+            paths.insert(start, (0, None));
+            queue.push(Reverse((0, start)));
+        }
+
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if distance > paths[node].0 {
+                continue; // already reached through a shorter path
+            }
+
+            for (next, weight) in &self.successors[node] {
+                let candidate = distance + weight;
+                let shorter = paths.get(next).is_none_or(|(known, _)| candidate < *known);
+
+                if shorter {
+                    paths.insert(next, (candidate, Some(node)));
+                    queue.push(Reverse((candidate, next)));
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example of day 7
+    fn steps() -> Graph<char> {
+        let mut graph = Graph::new();
+        for (from, to) in [
+            ('C', 'A'),
+            ('C', 'F'),
+            ('A', 'B'),
+            ('A', 'D'),
+            ('B', 'E'),
+            ('D', 'E'),
+            ('F', 'E'),
+        ] {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let mut graph = steps();
+
+        assert_eq!((graph.node_count(), graph.edge_count()), (6, 7));
+        assert_eq!(graph.roots(), vec![&'C']);
+        assert_eq!(graph.in_degree(&'E'), 3);
+        assert_eq!(graph.out_degree(&'A'), 2);
+        assert_eq!(
+            graph.predecessors(&'E').collect::<String>(),
+            "BDF".to_string()
+        );
+
+        assert!(graph.remove_node(&'C'));
+        assert!(!graph.remove_node(&'C'));
+        assert_eq!(graph.roots(), vec![&'A', &'F']);
+        assert_eq!(graph.edge_count(), 5);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = steps();
+
+        assert_eq!(
+            graph.topological_sort().map(String::from_iter),
+            Some("CABDFE".to_string())
+        );
+
+        graph.add_edge('E', 'C');
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[test]
+    fn test_walks() {
+        let graph = steps();
+
+        assert_eq!(String::from_iter(graph.bfs(&'C')), "CAFBDE");
+        assert_eq!(String::from_iter(graph.dfs(&'C')), "CABEDF");
+        assert_eq!(String::from_iter(graph.reachable(&'A')), "ABDE");
+        assert!(graph.is_reachable(&'F', &'E'));
+        assert!(!graph.is_reachable(&'E', &'F'));
+        assert!(graph.bfs(&'X').is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("a", "b", 7);
+        graph.add_weighted_edge("a", "c", 2);
+        graph.add_weighted_edge("c", "b", 3);
+        graph.add_weighted_edge("b", "d", 1);
+        graph.add_node("e");
+
+        let distances = graph.dijkstra(&"a");
+        assert_eq!(distances[&"b"], 5);
+        assert_eq!(distances[&"d"], 6);
+        assert!(!distances.contains_key(&"e"));
+
+        assert_eq!(
+            graph.shortest_path(&"a", &"d"),
+            Some((6, vec!["a", "c", "b", "d"]))
+        );
+        assert_eq!(graph.shortest_path(&"a", &"e"), None);
+        assert_eq!(graph.shortest_path(&"a", &"a"), Some((0, vec!["a"])));
+    }
+}
@@ -0,0 +1,372 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+//
+// Rectangular map of cells stored row by row. Cells are addressed by `(x, y)`
+// puzzle coordinates, `origin` is the coordinate of the top left cell, so maps
+// reaching into negative coordinates don't need to be shifted by hand.
+//
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    origin: (i64, i64),
+    cells: Vec<T>,
+}
+
+const NEIGHBORS4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBORS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            origin: (0, 0),
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // Cell values computed from their coordinates, in row-major order
+    pub fn from_fn<F: FnMut(i64, i64) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x as i64, y as i64)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Grid {
+            width,
+            height,
+            origin: (0, 0),
+            cells,
+        }
+    }
+
+    // Moves the top left cell to `(x, y)`, cells keep their values
+    pub fn with_origin(mut self, x: i64, y: i64) -> Self {
+        self.origin = (x, y);
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> (i64, i64) {
+        self.origin
+    }
+
+    // Coordinates of the bottom right cell
+    pub fn max(&self) -> (i64, i64) {
+        (
+            self.origin.0 + self.width as i64 - 1,
+            self.origin.1 + self.height as i64 - 1,
+        )
+    }
+
+    fn offset(&self, x: i64, y: i64) -> Option<usize> {
+        let (col, row) = (x - self.origin.0, y - self.origin.1);
+
+        if col < 0 || row < 0 || col >= self.width as i64 || row >= self.height as i64 {
+            return None;
+        }
+
+        Some(row as usize * self.width + col as usize)
+    }
+
+    fn coordinates(&self, offset: usize) -> (i64, i64) {
+        (
+            self.origin.0 + (offset % self.width) as i64,
+            self.origin.1 + (offset / self.width) as i64,
+        )
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.offset(x, y).is_some()
+    }
+
+    // Whether `(x, y)` is a cell of the outermost rows or columns
+    pub fn on_edge(&self, x: i64, y: i64) -> bool {
+        let (max_x, max_y) = self.max();
+
+        self.contains(x, y)
+            && (x == self.origin.0 || y == self.origin.1 || x == max_x || y == max_y)
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.offset(x, y).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.offset(x, y).map(move |offset| &mut self.cells[offset])
+    }
+
+    // Returns false, leaving the grid untouched, when `(x, y)` is out of bounds
+    pub fn set(&mut self, x: i64, y: i64, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Cells in row-major order with their coordinates
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| (self.coordinates(offset), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((i64, i64), &mut T)> {
+        let (width, origin) = (self.width, self.origin);
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| {
+                let x = origin.0 + (offset % width) as i64;
+                let y = origin.1 + (offset / width) as i64;
+                ((x, y), cell)
+            })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on zero, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // Up, left, right and down neighbors inside the grid
+    pub fn neighbors4(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS4)
+    }
+
+    // Same as `neighbors4` plus diagonals
+    pub fn neighbors8(&self, x: i64, y: i64) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.neighbors(x, y, &NEIGHBORS8)
+    }
+
+    fn neighbors(
+        &self,
+        x: i64,
+        y: i64,
+        deltas: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = (i64, i64)> + '_ {
+        deltas
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |(x, y)| self.contains(*x, *y))
+    }
+
+    // Read only window of `width` x `height` cells starting at `(x, y)`,
+    // clipped to the grid
+    pub fn view(&self, x: i64, y: i64, width: usize, height: usize) -> View<'_, T> {
+        let (max_x, max_y) = self.max();
+        let (min_x, min_y) = (x.max(self.origin.0), y.max(self.origin.1));
+        let end_x = (x + width as i64 - 1).min(max_x);
+        let end_y = (y + height as i64 - 1).min(max_y);
+
+        View {
+            grid: self,
+            origin: (min_x, min_y),
+            width: (end_x - min_x + 1).max(0) as usize,
+            height: (end_y - min_y + 1).max(0) as usize,
+        }
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            origin: self.origin,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(i64, i64)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i64, i64)) -> &T {
+        match self.offset(x, y) {
+            Some(offset) => &self.cells[offset],
+            None => panic!("({}, {}) is outside of the grid", x, y),
+        }
+    }
+}
+
+impl<T> IndexMut<(i64, i64)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i64, i64)) -> &mut T {
+        match self.offset(x, y) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("({}, {}) is outside of the grid", x, y),
+        }
+    }
+}
+
+// One line per row, cells written next to each other
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.view(self.origin.0, self.origin.1, self.width, self.height)
+            .fmt(f)
+    }
+}
+
+// Part of a grid, see `Grid::view`. Cells keep the coordinates of the grid.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (i64, i64),
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> (i64, i64) {
+        self.origin
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        let (col, row) = (x - self.origin.0, y - self.origin.1);
+
+        col >= 0 && row >= 0 && col < self.width as i64 && row < self.height as i64
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&'a T> {
+        if self.contains(x, y) {
+            self.grid.get(x, y)
+        } else {
+            None
+        }
+    }
+
+    // Cells in row-major order with their coordinates
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &'a T)> + '_ {
+        let grid = self.grid;
+
+        (0..self.height as i64)
+            .flat_map(move |row| (0..self.width as i64).map(move |col| (col, row)))
+            .map(move |(col, row)| {
+                let (x, y) = (self.origin.0 + col, self.origin.1 + row);
+                ((x, y), &grid[(x, y)])
+            })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.iter().map(|(_, cell)| cell)
+    }
+}
+
+impl<'a, T: fmt::Display> fmt::Display for View<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.height as i64 {
+            for col in 0..self.width as i64 {
+                write!(
+                    f,
+                    "{}",
+                    self.grid[(self.origin.0 + col, self.origin.1 + row)]
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_and_bounds() {
+        let mut grid = Grid::new(3, 2, '.');
+
+        assert!(grid.set(2, 1, '#'));
+        assert!(!grid.set(3, 1, '#'));
+        assert_eq!(grid.get(2, 1), Some(&'#'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid[(0, 0)], '.');
+        assert_eq!(grid.to_string(), "...\n..#\n");
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 2) is outside of the grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        let _ = grid[(0, 2)];
+    }
+
+    #[test]
+    fn test_offset_origin() {
+        let mut grid = Grid::from_fn(3, 3, |x, y| x + y * 3).with_origin(-1, -1);
+
+        assert_eq!(grid.max(), (1, 1));
+        assert_eq!(grid[(-1, -1)], 0);
+        assert_eq!(grid[(0, 0)], 4);
+        assert_eq!(grid.get(2, 0), None);
+
+        grid[(1, 1)] = 42;
+        assert_eq!(grid.iter().last(), Some(((1, 1), &42)));
+        assert!(grid.on_edge(-1, 0));
+        assert!(!grid.on_edge(0, 0));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbors4(1, 1).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbors8(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::from_fn(4, 3, |x, y| (x + y * 4) as u8);
+        let view = grid.view(2, 1, 5, 5);
+
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(
+            view.values().cloned().collect::<Vec<_>>(),
+            vec![6, 7, 10, 11]
+        );
+        assert_eq!(view.get(1, 1), None);
+        assert_eq!(view.to_string(), "67\n1011\n");
+        assert_eq!(grid.view(5, 5, 2, 2).values().count(), 0);
+    }
+}
@@ -0,0 +1,290 @@
+use crate::grid::Grid;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+pub const BLACK: Rgb = Rgb(0, 0, 0);
+pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+const PALETTE: [Rgb; 12] = [
+    Rgb(230, 25, 75),
+    Rgb(60, 180, 75),
+    Rgb(255, 225, 25),
+    Rgb(0, 130, 200),
+    Rgb(245, 130, 48),
+    Rgb(145, 30, 180),
+    Rgb(70, 240, 240),
+    Rgb(240, 50, 230),
+    Rgb(210, 245, 60),
+    Rgb(250, 190, 212),
+    Rgb(0, 128, 128),
+    Rgb(170, 110, 40),
+];
+
+// Distinct colors for numbered things, repeating after a dozen
+pub fn palette(idx: usize) -> Rgb {
+    PALETTE[idx % PALETTE.len()]
+}
+
+//
+// Black through red and yellow to white as `value` goes from zero to `max`,
+// for counts like how many claims cover an inch of fabric.
+//
+pub fn heat(value: u32, max: u32) -> Rgb {
+    if max == 0 {
+        return BLACK;
+    }
+
+    // three equal steps of 0..=255
+    let level = (value.min(max) as u64 * 765 / max as u64) as u32;
+    let channel = |from: u32| level.saturating_sub(from).min(255) as u8;
+
+    Rgb(channel(0), channel(255), channel(510))
+}
+
+//
+// Picture of a grid shaped puzzle state, for maps too big for the terminal.
+// Written as binary PPM, which most viewers open, or as PNG.
+//
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    // One pixel per cell, colored by `color`
+    pub fn from_grid<T, F: Fn(&T) -> Rgb>(grid: &Grid<T>, color: F) -> Image {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.values().map(color).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Pixels outside of the image are ignored
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    // Every pixel becomes a `factor` x `factor` square, for small maps
+    pub fn scale(&self, factor: usize) -> Image {
+        let mut scaled = Image::new(self.width * factor, self.height * factor, BLACK);
+
+        for y in 0..scaled.height {
+            for x in 0..scaled.width {
+                scaled.pixels[y * scaled.width + x] =
+                    self.pixels[(y / factor) * self.width + x / factor];
+            }
+        }
+
+        scaled
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+
+        for pixel in &self.pixels {
+            bytes.extend_from_slice(&[pixel.0, pixel.1, pixel.2]);
+        }
+
+        bytes
+    }
+
+    // 8 bit RGB PNG, image data is stored without compression
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // bit depth 8, color type RGB, default compression, filter and no interlace
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        // every scanline starts with its filter type, 0 is none
+        let mut scanlines = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.rows().take(self.height) {
+            scanlines.push(0);
+            for pixel in row {
+                scanlines.extend_from_slice(&[pixel.0, pixel.1, pixel.2]);
+            }
+        }
+
+        let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    // PNG when the path ends with `.png`, PPM otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let is_png = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+
+        if is_png {
+            fs::write(path, self.to_png())
+        } else {
+            fs::write(path, self.to_ppm())
+        }
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a 32K window, no preset dictionary, header checksum
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        stream.push(u8::from(last));
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::from_fn(2, 1, |x, _| x as u32);
+        let image = Image::from_grid(&grid, |count| heat(*count, 1));
+
+        assert_eq!(image.get(1, 0), Some(WHITE));
+        assert_eq!(image.get(2, 0), None);
+        assert_eq!(
+            image.to_ppm(),
+            b"P6\n2 1\n255\n\x00\x00\x00\xff\xff\xff".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_png() {
+        let mut image = Image::new(2, 2, BLACK);
+        image.set(1, 1, palette(0));
+        let png = image.to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        // IHDR: 13 bytes, 2x2, 8 bit RGB
+        assert_eq!(&png[8..16], b"\x00\x00\x00\x0dIHDR");
+        assert_eq!(
+            &png[16..29],
+            b"\x00\x00\x00\x02\x00\x00\x00\x02\x08\x02\x00\x00\x00"
+        );
+        assert_eq!(
+            &png[png.len() - 12..],
+            b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"
+        );
+
+        // IDAT: zlib header, single stored block of two 7 byte scanlines
+        let idat = &png[33..];
+        assert_eq!(&idat[4..8], b"IDAT");
+        assert_eq!(&idat[8..15], b"\x78\x01\x01\x0e\x00\xf1\xff");
+        assert_eq!(&idat[15..22], b"\x00\x00\x00\x00\x00\x00\x00");
+        assert_eq!(&idat[22..29], b"\x00\x00\x00\x00\xe6\x19\x4b");
+    }
+
+    #[test]
+    fn test_heat_and_scale() {
+        assert_eq!(heat(0, 4), BLACK);
+        assert_eq!(heat(2, 4), Rgb(255, 127, 0));
+        assert_eq!(heat(9, 4), WHITE);
+
+        let mut image = Image::new(2, 1, BLACK);
+        image.set(1, 0, WHITE);
+        let scaled = image.scale(2);
+
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.get(2, 1), Some(WHITE));
+        assert_eq!(scaled.get(1, 1), Some(BLACK));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::parse::ParseErrors;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// Overrides the directory puzzle inputs are looked up in
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS";
+
+// Where the puzzle input comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Day(u8),
+    Path(PathBuf),
+    Stdin,
+    Literal(String),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u8, searched: Vec<PathBuf> },
+    Unreadable(Source, io::Error),
+    InvalidUtf8(Source),
+    Empty(Source),
+    // read fine, but not an input of the puzzle
+    Invalid(Source, ParseErrors),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Day(day) => write!(f, "input of day {}", day),
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Literal(_) => write!(f, "<literal>"),
+        }
+    }
+}
+
+impl Source {
+    //
+    // Source named by the first command line argument: nothing means the
+    // day's default file, `-` is stdin and anything else is a path.
+    //
+    pub fn from_args<I: Iterator<Item = String>>(day: u8, mut args: I) -> Source {
+        match args.next().as_deref() {
+            None => Source::Day(day),
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+        }
+    }
+
+    // `dirs` are searched for `Source::Day` inputs, see `inputs_dirs`
+    pub fn read_from(&self, dirs: &[PathBuf]) -> Result<String, InputError> {
+        match self {
+            Source::Day(day) => read_input_file(&find_puzzle_input(*day, dirs)?),
+            Source::Path(path) => read_input_file(path),
+            Source::Stdin => {
+                let mut bytes = vec![];
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| InputError::Unreadable(Source::Stdin, err))?;
+                decode(Source::Stdin, bytes)
+            }
+            Source::Literal(text) => decode(self.clone(), text.clone().into_bytes()),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing { day, searched } => {
+                let searched = searched
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "input for day {} not found, searched: {}", day, searched)
+            }
+            InputError::Unreadable(source, err) => write!(f, "cannot read {}: {}", source, err),
+            InputError::InvalidUtf8(source) => write!(f, "{} is not valid UTF-8", source),
+            InputError::Empty(source) => write!(f, "{} is empty", source),
+            InputError::Invalid(source, errors) => write!(f, "invalid {}:\n{}", source, errors),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, err) => Some(err),
+            InputError::Invalid(_, errors) => Some(errors),
+            _ => None,
+        }
+    }
+}
+
+//
+// Directories searched for `<day>.txt`, in order. An explicitly configured
+// root (CLI flag first, then $AOC_INPUTS) is the only candidate, otherwise
+// ./inputs is tried before `default`, the inputs directory of the year's
+// crate, so puzzles can be run from any directory.
+//
+pub fn inputs_dirs(root: Option<&Path>, default: &Path) -> Vec<PathBuf> {
+    if let Some(root) = root {
+        return vec![root.to_path_buf()];
+    }
+
+    if let Some(root) = env::var_os(INPUTS_DIR_ENV) {
+        return vec![PathBuf::from(root)];
+    }
+
+    vec![PathBuf::from("inputs"), default.to_path_buf()]
+}
+
+// First file called `name` in one of the inputs directories
+pub fn find_in_inputs(name: &str, dirs: &[PathBuf]) -> Option<PathBuf> {
+    dirs.iter()
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+pub fn find_puzzle_input(day: u8, dirs: &[PathBuf]) -> Result<PathBuf, InputError> {
+    let searched: Vec<PathBuf> = dirs
+        .iter()
+        .map(|dir| dir.join(format!("{}.txt", day)))
+        .collect();
+
+    match searched.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(InputError::Missing { day, searched }),
+    }
+}
+
+fn decode(source: Source, bytes: Vec<u8>) -> Result<String, InputError> {
+    let text = String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8(source.clone()))?;
+
+    if text.trim().is_empty() {
+        return Err(InputError::Empty(source));
+    }
+
+    Ok(text)
+}
+
+pub fn read_input_file(path: &Path) -> Result<String, InputError> {
+    let source = Source::Path(path.to_path_buf());
+    let bytes = fs::read(path).map_err(|err| InputError::Unreadable(source.clone(), err))?;
+
+    decode(source, bytes)
+}
+
+pub fn load_puzzle_input_from(day: u8, dirs: &[PathBuf]) -> Result<String, InputError> {
+    Source::Day(day).read_from(dirs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_from_configured_root() {
+        let dir = fixture_dir("root");
+        fs::write(dir.join("1.txt"), "+1\n-2\n").unwrap();
+
+        let dirs = inputs_dirs(Some(&dir), Path::new("unused"));
+        assert_eq!(dirs, vec![dir.clone()]);
+        assert_eq!(load_puzzle_input_from(1, &dirs).unwrap(), "+1\n-2\n");
+
+        match load_puzzle_input_from(2, &dirs) {
+            Err(InputError::Missing { day, searched }) => {
+                assert_eq!(day, 2);
+                assert_eq!(searched, vec![dir.join("2.txt")]);
+            }
+            other => panic!("expected missing input, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_invalid_inputs() {
+        let dir = fixture_dir("invalid");
+        fs::write(dir.join("1.txt"), " \n\n").unwrap();
+        fs::write(dir.join("2.txt"), [0xff, 0xfe, 0x00]).unwrap();
+        let dirs = vec![dir.clone()];
+
+        assert!(matches!(
+            load_puzzle_input_from(1, &dirs),
+            Err(InputError::Empty(_))
+        ));
+        assert!(matches!(
+            load_puzzle_input_from(2, &dirs),
+            Err(InputError::InvalidUtf8(_))
+        ));
+        assert!(matches!(
+            read_input_file(&dir),
+            Err(InputError::Unreadable(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_sources() {
+        let dir = fixture_dir("sources");
+        fs::write(dir.join("5.txt"), "dabAcCaCBAcCcaDA\n").unwrap();
+        let dirs = vec![dir.clone()];
+
+        let args = |line: &str| {
+            line.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(Source::from_args(5, args("").into_iter()), Source::Day(5));
+        assert_eq!(Source::from_args(5, args("-").into_iter()), Source::Stdin);
+        assert_eq!(
+            Source::from_args(5, args("polymer.txt").into_iter()),
+            Source::Path(PathBuf::from("polymer.txt"))
+        );
+
+        assert_eq!(
+            Source::Day(5).read_from(&dirs).unwrap(),
+            "dabAcCaCBAcCcaDA\n"
+        );
+        assert_eq!(
+            Source::Path(dir.join("5.txt")).read_from(&[]).unwrap(),
+            "dabAcCaCBAcCcaDA\n"
+        );
+        assert_eq!(
+            Source::Literal(String::from("aA")).read_from(&[]).unwrap(),
+            "aA"
+        );
+        assert!(matches!(
+            Source::Literal(String::new()).read_from(&[]),
+            Err(InputError::Empty(Source::Literal(_)))
+        ));
+    }
+
+    #[test]
+    fn test_default_dir_fallback() {
+        let dirs = inputs_dirs(None, Path::new("/aoc/2018/inputs"));
+
+        if env::var_os(INPUTS_DIR_ENV).is_none() {
+            assert_eq!(
+                dirs,
+                vec![PathBuf::from("inputs"), PathBuf::from("/aoc/2018/inputs")]
+            );
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+use std::fmt::Write;
+
+//
+// Just enough JSON to emit reports: values are built in memory and written
+// with stable key order, so output of two runs can be diffed.
+//
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object() -> Json {
+        Json::Object(vec![])
+    }
+
+    // Appends a key to an object, ignored for other values
+    pub fn with(mut self, key: &str, value: impl Into<Json>) -> Json {
+        if let Json::Object(entries) = &mut self {
+            entries.push((key.to_string(), value.into()));
+        }
+        self
+    }
+
+    pub fn pretty(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, Some(0));
+        out
+    }
+
+    fn write(&self, out: &mut String, indent: Option<usize>) {
+        let newline = |out: &mut String, level: usize| {
+            if indent.is_some() {
+                out.push('\n');
+                out.push_str(&"  ".repeat(level));
+            }
+        };
+        let level = indent.unwrap_or(0);
+        let nested = indent.map(|level| level + 1);
+        let separator = if indent.is_some() { ": " } else { ":" };
+
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Int(value) => out.push_str(&value.to_string()),
+            Json::Float(value) if value.is_finite() => out.push_str(&value.to_string()),
+            Json::Float(_) => out.push_str("null"),
+            Json::String(value) => write_string(out, value),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) => {
+                out.push('[');
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        out.push(',');
+                    }
+                    newline(out, level + 1);
+                    item.write(out, nested);
+                }
+                newline(out, level);
+                out.push(']');
+            }
+            Json::Object(entries) if entries.is_empty() => out.push_str("{}"),
+            Json::Object(entries) => {
+                out.push('{');
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx > 0 {
+                        out.push(',');
+                    }
+                    newline(out, level + 1);
+                    write_string(out, key);
+                    out.push_str(separator);
+                    value.write(out, nested);
+                }
+                newline(out, level);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for chr in value.chars() {
+        match chr {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            chr if (chr as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", chr as u32);
+            }
+            chr => out.push(chr),
+        }
+    }
+    out.push('"');
+}
+
+// Compact single line form
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, None);
+        f.write_str(&out)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Json {
+        Json::Bool(value)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Json {
+        Json::Int(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Json {
+        Json::Int(value as i64)
+    }
+}
+
+impl From<u16> for Json {
+    fn from(value: u16) -> Json {
+        Json::Int(value as i64)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Json {
+        Json::Int(value as i64)
+    }
+}
+
+impl From<u128> for Json {
+    fn from(value: u128) -> Json {
+        Json::Int(value.min(i64::MAX as u128) as i64)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Json {
+        Json::Float(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Json {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Json {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(items: Vec<T>) -> Json {
+        Json::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Json {
+        value.map(Into::into).unwrap_or(Json::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact() {
+        let json = Json::object()
+            .with("day", 7u8)
+            .with("answer", "CABDFE")
+            .with("parts", vec![1i64, 2])
+            .with("error", Json::Null)
+            .with("note", "say \"hi\"\n");
+
+        assert_eq!(
+            json.to_string(),
+            r#"{"day":7,"answer":"CABDFE","parts":[1,2],"error":null,"note":"say \"hi\"\n"}"#
+        );
+    }
+
+    #[test]
+    fn test_pretty() {
+        let json = Json::object()
+            .with("days", vec![Json::object().with("day", 1u8)])
+            .with("empty", Json::Array(vec![]));
+
+        assert_eq!(
+            json.pretty(),
+            "{\n  \"days\": [\n    {\n      \"day\": 1\n    }\n  ],\n  \"empty\": []\n}"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+//
+// Known good answers, one per line:
+//
+// # id  part  answer
+// 5     1     9462
+// 7     1     CFMNLOAHRKPTWBJSYZVGUQXIDE
+//
+// The id is the day number for puzzle inputs, everything after the part
+// column is the expected answer.
+//
+#[derive(Debug, Default)]
+pub struct Ledger {
+    entries: BTreeMap<(String, u8), String>,
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    Unreadable(PathBuf, io::Error),
+    Malformed { line: usize, text: String },
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LedgerError::Unreadable(path, err) => {
+                write!(f, "cannot read {}: {}", path.display(), err)
+            }
+            LedgerError::Malformed { line, text } => {
+                write!(
+                    f,
+                    "line {}: expected `<id> <part> <answer>`, got {:?}",
+                    line, text
+                )
+            }
+        }
+    }
+}
+
+impl Error for LedgerError {}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+// Splits off the first whitespace separated column
+fn split_column(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start();
+    let end = text.find(char::is_whitespace)?;
+
+    Some((&text[..end], text[end..].trim()))
+}
+
+impl Ledger {
+    pub fn new() -> Ledger {
+        Ledger {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Ledger, LedgerError> {
+        let mut ledger = Ledger::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || LedgerError::Malformed {
+                line: idx + 1,
+                text: line.to_string(),
+            };
+
+            let (id, rest) = split_column(line).ok_or_else(malformed)?;
+            let (part, answer) = split_column(rest).ok_or_else(malformed)?;
+            let part = part.parse::<u8>().map_err(|_| malformed())?;
+
+            if answer.is_empty() {
+                return Err(malformed());
+            }
+
+            ledger.insert(id, part, answer);
+        }
+
+        Ok(ledger)
+    }
+
+    pub fn load(path: &Path) -> Result<Ledger, LedgerError> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| LedgerError::Unreadable(path.to_path_buf(), err))?;
+
+        Ledger::parse(&text)
+    }
+
+    pub fn insert(&mut self, id: &str, part: u8, answer: &str) {
+        self.entries
+            .insert((id.to_string(), part), answer.to_string());
+    }
+
+    pub fn expected(&self, id: &str, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(id.to_string(), part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn check(&self, id: &str, part: u8, answer: &str) -> Verdict {
+        match self.expected(id, part) {
+            Some(expected) if expected == answer.trim() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_check() {
+        let ledger = Ledger::parse(
+            "
+            # id part answer
+            5 1 10
+            5	2   4
+            7 1 CABDFE
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(ledger.expected("5", 2), Some("4"));
+        assert_eq!(ledger.check("5", 1, "10"), Verdict::Pass);
+        assert_eq!(ledger.check("7", 1, "CABDFE\n"), Verdict::Pass);
+        assert_eq!(
+            ledger.check("5", 2, "6"),
+            Verdict::Fail {
+                expected: String::from("4")
+            }
+        );
+        assert_eq!(ledger.check("7", 2, "15"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        match Ledger::parse("1 1 493\n1 x 413\n") {
+            Err(LedgerError::Malformed { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected malformed line, got {:?}", other),
+        }
+        assert!(Ledger::parse("1 2\n").is_err());
+    }
+}
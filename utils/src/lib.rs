@@ -0,0 +1,34 @@
+pub mod config;
+pub mod counter;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod fuzz;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod ledger;
+pub mod parse;
+pub mod property;
+pub mod random;
+pub mod registry;
+pub mod render;
+pub mod solution;
+pub mod trace;
+
+pub use config::{Config, ConfigError, Configure, Params};
+pub use counter::Counter;
+pub use error::Error;
+pub use geometry::{Point2, Point3, Rect};
+pub use graph::Graph;
+pub use grid::Grid;
+pub use image::{Image, Rgb};
+pub use input::{load_puzzle_input_from, InputError, Source};
+pub use parse::{parse_lines, ParseError};
+pub use random::Rng;
+pub use registry::Registry;
+pub use render::{Canvas, Color, Render, Style};
+pub use solution::{Answer, Output, Prepared, Puzzle, Solution};
@@ -0,0 +1,309 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+//
+// Where and why a line of puzzle input could not be parsed. `FromStr` impls
+// only see a single line, so they report line 1 and `parse_lines` moves the
+// error to the line it came from. Line 0 is the input as a whole, for inputs
+// whose lines are fine on their own but make no puzzle together.
+//
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    // None at the end of the line, or of the input
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: &str, found: Option<&str>) -> ParseError {
+        ParseError {
+            line: 1,
+            column,
+            expected: expected.to_string(),
+            found: found.map(String::from),
+        }
+    }
+
+    pub fn input(expected: &str, found: Option<&str>) -> ParseError {
+        ParseError {
+            line: 0,
+            column: 0,
+            expected: expected.to_string(),
+            found: found.map(String::from),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // the whole input ends where a line would
+        let end = if self.line == 0 {
+            write!(f, "input")?;
+            "end of input"
+        } else {
+            write!(f, "line {}, column {}", self.line, self.column)?;
+            "end of line"
+        };
+
+        write!(f, ": expected {}, found ", self.expected)?;
+        match &self.found {
+            Some(found) => write!(f, "{:?}", found),
+            None => write!(f, "{}", end),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Every bad line of an input, in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseErrors(pub Vec<ParseError>);
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, err) in self.0.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ParseError> for ParseErrors {
+    fn from(err: ParseError) -> ParseErrors {
+        ParseErrors(vec![err])
+    }
+}
+
+impl Error for ParseErrors {}
+
+//
+// Parses every non blank line, collecting the errors of all bad lines instead
+// of stopping at the first one.
+//
+pub fn parse_lines<T: FromStr<Err = ParseError>>(text: &str) -> Result<Vec<T>, ParseErrors> {
+    parse_lines_with(text, str::parse)
+}
+
+// Same as `parse_lines`, for lines that need more than `FromStr` to parse
+// (the size of a day's grid, ...)
+pub fn parse_lines_with<T, F>(text: &str, mut parse: F) -> Result<Vec<T>, ParseErrors>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    let mut items = vec![];
+    let mut errors = vec![];
+
+    for (idx, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse(line) {
+            Ok(item) => items.push(item),
+            Err(err) => errors.push(err.at_line(idx + 1)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(items)
+    } else {
+        Err(ParseErrors(errors))
+    }
+}
+
+//
+// Cursor over a single line for hand written `FromStr` impls. Tokens may be
+// preceded by whitespace, errors point at the column the cursor stopped at.
+//
+pub struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(text: &'a str) -> Scanner<'a> {
+        Scanner { text, pos: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    // 1-based, counted in characters
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    // Error at the current position, `found` is the next word
+    pub fn error(&self, expected: &str) -> ParseError {
+        let found = self.rest().split_whitespace().next();
+
+        ParseError::new(self.column(), expected, found)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    // Consumes `token` if the line continues with it
+    pub fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("{:?}", token)))
+        }
+    }
+
+    // Optionally signed decimal number
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let sign = if rest.starts_with(['-', '+']) { 1 } else { 0 };
+        let digits = rest[sign..]
+            .find(|chr: char| !chr.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        match rest[..sign + digits].parse() {
+            Ok(number) if digits > 0 => {
+                self.pos += sign + digits;
+                Ok(number)
+            }
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    // Single character accepted by `accept`
+    pub fn char_if<F: Fn(char) -> bool>(
+        &mut self,
+        expected: &str,
+        accept: F,
+    ) -> Result<char, ParseError> {
+        self.skip_whitespace();
+
+        match self.rest().chars().next() {
+            Some(chr) if accept(chr) => {
+                self.pos += chr.len_utf8();
+                Ok(chr)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    // Everything up to, not including, `delimiter`
+    pub fn take_until(&mut self, delimiter: &str) -> Result<&'a str, ParseError> {
+        match self.rest().find(delimiter) {
+            Some(len) => {
+                let taken = &self.rest()[..len];
+                self.pos += len;
+                Ok(taken)
+            }
+            None => Err(ParseError::new(
+                self.column() + self.rest().chars().count(),
+                &format!("{:?}", delimiter),
+                None,
+            )),
+        }
+    }
+
+    // Only whitespace may be left
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(i32, char);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(line: &str) -> Result<Pair, ParseError> {
+            let mut scanner = Scanner::new(line);
+
+            scanner.expect("pair")?;
+            let number = scanner.number()?;
+            scanner.expect(",")?;
+            let letter = scanner.char_if("a letter", |chr| chr.is_ascii_alphabetic())?;
+            scanner.end()?;
+
+            Ok(Pair(number, letter))
+        }
+    }
+
+    #[test]
+    fn test_scanner() {
+        assert_eq!("pair -12, x".parse(), Ok(Pair(-12, 'x')));
+        assert_eq!(
+            "pair 12; x".parse::<Pair>(),
+            Err(ParseError::new(8, "\",\"", Some(";")))
+        );
+        assert_eq!(
+            "pair x".parse::<Pair>(),
+            Err(ParseError::new(6, "a number", Some("x")))
+        );
+        assert_eq!(
+            "pair 1,".parse::<Pair>(),
+            Err(ParseError::new(8, "a letter", None))
+        );
+
+        let mut scanner = Scanner::new("[1518-11-01 00:00] wakes up");
+        scanner.expect("[").unwrap();
+        assert_eq!(scanner.take_until("]"), Ok("1518-11-01 00:00"));
+        assert_eq!(scanner.column(), 18);
+        assert!(scanner.take_until("#").is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_collects_errors() {
+        let errors = parse_lines::<Pair>("pair 1, a\npair x, b\n\npair 3, c d\n").unwrap_err();
+
+        assert_eq!(errors.0.len(), 2);
+        assert_eq!(
+            errors.to_string(),
+            "line 2, column 6: expected a number, found \"x,\"\n\
+             line 4, column 11: expected end of line, found \"d\""
+        );
+        assert_eq!(
+            parse_lines::<Pair>("pair 1, a\n\npair 2, b\n"),
+            Ok(vec![Pair(1, 'a'), Pair(2, 'b')])
+        );
+        assert_eq!(
+            ParseErrors::from(ParseError::input("a pair", None)).to_string(),
+            "input: expected a pair, found end of input"
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use crate::random::Rng;
+use std::env;
+use std::fmt::Debug;
+
+//
+// Property based testing: a property is checked against many random values
+// and the first value it fails for is shrunk to a small counterexample before
+// the test fails. Values come from a generator closure `(rng, size) -> T`,
+// sizes grow from case to case so small values are tried first.
+//
+//   property::check("reacted twice", |rng, size| polymer(rng, size), |polymer| {
+//       react(&react(polymer)) == react(polymer)
+//   });
+//
+// Runs are reproducible: the seed is fixed unless `AOC_PROPERTY_SEED` is set,
+// and a failure reports the seed it was found with.
+//
+
+// Name of the environment variable overriding the seed
+pub const SEED_VAR: &str = "AOC_PROPERTY_SEED";
+
+const DEFAULT_SEED: u64 = 2018;
+
+// Values a failing value can be replaced with, each one a little simpler
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($type:ty),*) => {$(
+        impl Shrink for $type {
+            fn shrink(&self) -> Vec<$type> {
+                let mut smaller = vec![];
+                for candidate in [0, *self / 2, self.saturating_sub(1)] {
+                    if candidate < *self && !smaller.contains(&candidate) {
+                        smaller.push(candidate);
+                    }
+                }
+                smaller
+            }
+        }
+    )*};
+}
+
+macro_rules! shrink_signed {
+    ($($type:ty),*) => {$(
+        impl Shrink for $type {
+            fn shrink(&self) -> Vec<$type> {
+                let mut smaller = vec![];
+                let toward_zero = *self - self.signum();
+                for candidate in [0, self.saturating_neg(), *self / 2, toward_zero] {
+                    let simpler = candidate.unsigned_abs() < self.unsigned_abs();
+                    if simpler && !smaller.contains(&candidate) {
+                        smaller.push(candidate);
+                    }
+                }
+                // same size, but positive numbers are simpler
+                if *self < 0 && *self != <$type>::MIN {
+                    smaller.insert(0, -*self);
+                }
+                smaller
+            }
+        }
+    )*};
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+shrink_signed!(i8, i16, i32, i64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<bool> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+// Toward 'a', keeping the case of letters
+impl Shrink for char {
+    fn shrink(&self) -> Vec<char> {
+        match *self {
+            'a' | 'A' => vec![],
+            chr if chr.is_ascii_uppercase() => vec!['A'],
+            _ => vec!['a'],
+        }
+    }
+}
+
+//
+// Shorter vectors first: empty, either half, without one element. Then the
+// same length with one element shrunk.
+//
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut smaller = vec![];
+        if self.is_empty() {
+            return smaller;
+        }
+
+        smaller.push(vec![]);
+        if self.len() > 2 {
+            let half = self.len() / 2;
+            smaller.push(self[..half].to_vec());
+            smaller.push(self[half..].to_vec());
+        }
+        if self.len() > 1 {
+            for idx in 0..self.len() {
+                let mut without = self.clone();
+                without.remove(idx);
+                smaller.push(without);
+            }
+        }
+
+        for (idx, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut changed = self.clone();
+                changed[idx] = simpler;
+                smaller.push(changed);
+            }
+        }
+
+        smaller
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<String> {
+        self.chars()
+            .collect::<Vec<char>>()
+            .shrink()
+            .into_iter()
+            .map(String::from_iter)
+            .collect()
+    }
+}
+
+// One component at a time
+macro_rules! shrink_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<$($name: Shrink + Clone),+> Shrink for ($($name,)+) {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![];
+                $(
+                    for simpler in self.$idx.shrink() {
+                        let mut changed = self.clone();
+                        changed.$idx = simpler;
+                        smaller.push(changed);
+                    }
+                )+
+                smaller
+            }
+        }
+    };
+}
+
+shrink_tuple!(A 0, B 1);
+shrink_tuple!(A 0, B 1, C 2);
+shrink_tuple!(A 0, B 1, C 2, D 3);
+
+// What a property returns, `false` or an error message is a failure
+pub trait Outcome {
+    fn failure(self) -> Option<String>;
+}
+
+impl Outcome for bool {
+    fn failure(self) -> Option<String> {
+        if self {
+            None
+        } else {
+            Some(String::from("property returned false"))
+        }
+    }
+}
+
+impl Outcome for Result<(), String> {
+    fn failure(self) -> Option<String> {
+        self.err()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub cases: usize,
+    // size of the last case, the first one has size 1
+    pub max_size: usize,
+    // how many times a counterexample is replaced by a simpler one
+    pub max_shrinks: usize,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        let seed = env::var(SEED_VAR)
+            .ok()
+            .and_then(|seed| seed.trim().parse().ok())
+            .unwrap_or(DEFAULT_SEED);
+
+        Config {
+            cases: 100,
+            max_size: 50,
+            max_shrinks: 1000,
+            seed,
+        }
+    }
+}
+
+// Smallest failing value found and why it fails
+#[derive(Debug, PartialEq)]
+pub struct Failure<T> {
+    pub value: T,
+    pub reason: String,
+    pub case: usize,
+    pub shrinks: usize,
+}
+
+impl Config {
+    pub fn cases(self, cases: usize) -> Config {
+        Config { cases, ..self }
+    }
+
+    pub fn max_size(self, max_size: usize) -> Config {
+        Config { max_size, ..self }
+    }
+
+    // Runs the property for every case, None when it always held
+    pub fn run<T, G, P, O>(&self, generate: G, property: P) -> Option<Failure<T>>
+    where
+        T: Shrink,
+        G: Fn(&mut Rng, usize) -> T,
+        P: Fn(&T) -> O,
+        O: Outcome,
+    {
+        let mut rng = Rng::new(self.seed);
+
+        for case in 0..self.cases {
+            let size =
+                1 + case * self.max_size.saturating_sub(1) / self.cases.max(2).saturating_sub(1);
+            let value = generate(&mut rng, size);
+
+            if let Some(reason) = property(&value).failure() {
+                return Some(self.shrink(value, reason, case, &property));
+            }
+        }
+
+        None
+    }
+
+    // Greedy: the first simpler value that still fails replaces the current one
+    fn shrink<T, P, O>(&self, value: T, reason: String, case: usize, property: &P) -> Failure<T>
+    where
+        T: Shrink,
+        P: Fn(&T) -> O,
+        O: Outcome,
+    {
+        let mut failure = Failure {
+            value,
+            reason,
+            case,
+            shrinks: 0,
+        };
+
+        while failure.shrinks < self.max_shrinks {
+            let simpler = failure.value.shrink().into_iter().find_map(|candidate| {
+                property(&candidate)
+                    .failure()
+                    .map(|reason| (candidate, reason))
+            });
+
+            match simpler {
+                Some((value, reason)) => {
+                    failure.value = value;
+                    failure.reason = reason;
+                    failure.shrinks += 1;
+                }
+                None => break,
+            }
+        }
+
+        failure
+    }
+
+    // Panics with the shrunk counterexample when the property fails
+    pub fn check<T, G, P, O>(&self, name: &str, generate: G, property: P)
+    where
+        T: Shrink + Debug,
+        G: Fn(&mut Rng, usize) -> T,
+        P: Fn(&T) -> O,
+        O: Outcome,
+    {
+        if let Some(failure) = self.run(generate, property) {
+            panic!(
+                "property {:?} failed at case {} ({}={}, shrunk {} times)\n  value: {:?}\n  {}",
+                name,
+                failure.case,
+                SEED_VAR,
+                self.seed,
+                failure.shrinks,
+                failure.value,
+                failure.reason
+            );
+        }
+    }
+}
+
+// `Config::default().check(...)`
+pub fn check<T, G, P, O>(name: &str, generate: G, property: P)
+where
+    T: Shrink + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+    P: Fn(&T) -> O,
+    O: Outcome,
+{
+    Config::default().check(name, generate, property)
+}
+
+// Up to `size` values of `item`
+pub fn vec_of<T, F: FnMut(&mut Rng) -> T>(rng: &mut Rng, size: usize, mut item: F) -> Vec<T> {
+    let len = rng.below(size + 1);
+
+    (0..len).map(|_| item(rng)).collect()
+}
+
+// Up to `size` characters picked from `alphabet`
+pub fn string_of(rng: &mut Rng, size: usize, alphabet: &str) -> String {
+    let chars: Vec<char> = alphabet.chars().collect();
+
+    vec_of(rng, size, |rng| *rng.choose(&chars).unwrap())
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_values() {
+        assert_eq!(10u32.shrink(), vec![0, 5, 9]);
+        assert_eq!(1u32.shrink(), vec![0]);
+        assert!(0u8.shrink().is_empty());
+        assert_eq!((-4i32).shrink(), vec![4, 0, -2, -3]);
+        assert_eq!('X'.shrink(), vec!['A']);
+        assert_eq!(
+            vec![3u8, 0].shrink(),
+            vec![vec![], vec![0], vec![3], vec![0, 0], vec![1, 0], vec![2, 0]]
+        );
+        assert_eq!((true, 2u8).shrink(), vec![(false, 2), (true, 0), (true, 1)]);
+    }
+
+    #[test]
+    fn test_shrinks_to_smallest_counterexample() {
+        let config = Config::default();
+        let failure = config
+            .run(
+                |rng, size| vec_of(rng, size, |rng| rng.below(100) as u32),
+                |numbers: &Vec<u32>| numbers.iter().sum::<u32>() < 50,
+            )
+            .unwrap();
+
+        // no element can be removed or made smaller without passing
+        assert_eq!(failure.value.iter().sum::<u32>(), 50);
+        assert_eq!(failure.reason, "property returned false");
+    }
+
+    #[test]
+    fn test_passing_property() {
+        let config = Config::default().cases(20).max_size(10);
+
+        let failure = config.run(
+            |rng, size| string_of(rng, size, "ab"),
+            |text: &String| {
+                if text.len() <= 10 {
+                    Ok(())
+                } else {
+                    Err(format!("{} characters", text.len()))
+                }
+            },
+        );
+        assert_eq!(failure, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "value: \"b\"")]
+    fn test_check_panics_with_counterexample() {
+        check(
+            "no b",
+            |rng, size| string_of(rng, size, "ab"),
+            |text: &String| !text.contains('b'),
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//
+// Small seeded random number generator (SplitMix64) for generating puzzle
+// inputs. The same seed always gives the same numbers, on every platform, so
+// a generated input can be reproduced from its seed alone. Not meant for
+// anything where the numbers must be unpredictable.
+//
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    // Seed taken from the clock, for when any input will do
+    pub fn time_seed() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in `0..n`, `n` must not be zero
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Rng::below(0)");
+
+        let n = n as u64;
+        // multiples of `n` only, so every value is equally likely
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % n) as usize;
+            }
+        }
+    }
+
+    // Uniform in `min..=max`
+    pub fn between(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "Rng::between({}, {})", min, max);
+
+        let span = (max - min) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        min + self.below(span as usize + 1) as i64
+    }
+
+    // True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        // 53 random bits, the precision of an f64
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.below(items.len())])
+        }
+    }
+
+    // Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx + 1));
+        }
+    }
+
+    // Lowercase ASCII letter
+    pub fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splitmix64() {
+        let mut rng = Rng::new(0);
+
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+        assert_eq!(rng.next_u64(), 0x06c4_5d18_8009_454f);
+    }
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let mut one = Rng::new(2018);
+        let mut two = Rng::new(2018);
+
+        let numbers: Vec<_> = (0..100).map(|_| one.between(-5, 5)).collect();
+        assert_eq!(
+            numbers,
+            (0..100).map(|_| two.between(-5, 5)).collect::<Vec<_>>()
+        );
+        assert!(numbers.iter().all(|n| (-5..=5).contains(n)));
+        assert!(numbers.contains(&-5) && numbers.contains(&5));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(7);
+        let mut items: Vec<_> = (0..20).collect();
+
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert_eq!(rng.choose::<u8>(&[]), None);
+        assert!(rng.letter().is_ascii_lowercase());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::input;
+use crate::solution::{Puzzle, Solution};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+//
+// Days of a single year: day number => implementation, ordered by day. Every
+// year's crate builds one, pointing `inputs_dir` at its own inputs.
+//
+pub struct Registry {
+    year: u16,
+    inputs_dir: PathBuf,
+    days: BTreeMap<u8, Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn new(year: u16, inputs_dir: &Path) -> Registry {
+        Registry {
+            year,
+            inputs_dir: inputs_dir.to_path_buf(),
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn inputs_dir(&self) -> &Path {
+        &self.inputs_dir
+    }
+
+    // `root` overrides the year's inputs directory, see `input::inputs_dirs`
+    pub fn inputs_dirs(&self, root: Option<&Path>) -> Vec<PathBuf> {
+        input::inputs_dirs(root, &self.inputs_dir)
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self, solution: S) {
+        self.days.insert(S::DAY, Box::new(solution));
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Puzzle> {
+        self.days.get(&day).map(|puzzle| puzzle.as_ref())
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        self.days.keys().cloned().collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+        self.days.values().map(|puzzle| puzzle.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 42;
+        const TITLE: &'static str = "Echo";
+
+        type Input = String;
+        type Answer1 = String;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &str) -> Result<String, Error> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part_one(&self, input: &String) -> Result<String, Error> {
+            Ok(input.clone())
+        }
+
+        fn part_two(&self, input: &String) -> Result<usize, Error> {
+            match input.len() {
+                0 => Err(Error::no_answer("nothing to count")),
+                len => Ok(len),
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_and_run() {
+        let mut registry = Registry::new(2018, Path::new("inputs"));
+        registry.register(Echo);
+
+        assert_eq!(registry.year(), 2018);
+        assert_eq!(registry.days(), vec![42]);
+        assert!(registry.get(1).is_none());
+
+        let puzzle = registry.get(42).unwrap();
+        assert_eq!(puzzle.title(), "Echo");
+
+        let prepared = puzzle.prepare(" hello\n").unwrap();
+        assert_eq!(prepared.part_one().unwrap().answer, "hello");
+        assert_eq!(prepared.part_two().unwrap().answer, "5");
+
+        let prepared = puzzle.prepare("\n").unwrap();
+        assert_eq!(
+            prepared.part_two().unwrap_err().to_string(),
+            "no answer: nothing to count"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::grid::Grid;
+use std::fmt;
+
+// How a canvas is turned into text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    // Characters only, colors are dropped
+    Plain,
+    // Colors as ANSI escape sequences, for terminals
+    Ansi,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    Bold,
+}
+
+const PALETTE: [Color; 6] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+];
+
+impl Color {
+    // Distinct colors for numbered things, e.g. claims or coordinates
+    pub fn cycle(idx: usize) -> Color {
+        PALETTE[idx % PALETTE.len()]
+    }
+
+    fn ansi_code(self) -> &'static str {
+        match self {
+            Color::Default => "0",
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Blue => "34",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+            Color::Gray => "90",
+            Color::Bold => "1",
+        }
+    }
+}
+
+//
+// Text drawn one character at a time, lines and columns past the limits are
+// dropped so a 1000x1000 map doesn't flood the terminal. `Display` writes the
+// canvas in its style and notes what was clipped.
+//
+pub struct Canvas {
+    style: Style,
+    max_width: usize,
+    max_height: usize,
+    lines: Vec<Vec<(char, Color)>>,
+    current: Vec<(char, Color)>,
+    // size of everything drawn, clipped or not
+    width: usize,
+    current_width: usize,
+    height: usize,
+}
+
+impl Canvas {
+    pub fn new(style: Style) -> Canvas {
+        Canvas {
+            style,
+            max_width: usize::MAX,
+            max_height: usize::MAX,
+            lines: vec![],
+            current: vec![],
+            width: 0,
+            current_width: 0,
+            height: 0,
+        }
+    }
+
+    // Keeps at most `width` characters of at most `height` lines
+    pub fn with_limits(mut self, width: usize, height: usize) -> Self {
+        self.max_width = width;
+        self.max_height = height;
+        self
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    pub fn put(&mut self, chr: char, color: Color) {
+        if self.current_width < self.max_width {
+            self.current.push((chr, color));
+        }
+        self.current_width += 1;
+    }
+
+    pub fn colored(&mut self, text: &str, color: Color) {
+        for chr in text.chars() {
+            self.put(chr, color);
+        }
+    }
+
+    pub fn text(&mut self, text: &str) {
+        self.colored(text, Color::Default);
+    }
+
+    pub fn newline(&mut self) {
+        let line = std::mem::take(&mut self.current);
+        if self.lines.len() < self.max_height {
+            self.lines.push(line);
+        }
+
+        self.width = self.width.max(self.current_width);
+        self.current_width = 0;
+        self.height += 1;
+    }
+
+    // Whole line of uncolored text
+    pub fn line(&mut self, text: &str) {
+        self.text(text);
+        self.newline();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0 && self.current_width == 0
+    }
+
+    // Width and height of everything drawn, including what was clipped
+    pub fn size(&self) -> (usize, usize) {
+        let unfinished = usize::from(self.current_width > 0);
+
+        (self.width.max(self.current_width), self.height + unfinished)
+    }
+
+    pub fn is_clipped(&self) -> bool {
+        let (width, height) = self.size();
+
+        width > self.max_width || height > self.max_height
+    }
+
+    pub fn draw<R: Render + ?Sized>(&mut self, item: &R) {
+        item.render(self);
+    }
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unfinished = Some(&self.current).filter(|line| !line.is_empty());
+
+        for line in self.lines.iter().chain(unfinished) {
+            let mut color = Color::Default;
+
+            for &(chr, cell_color) in line {
+                if self.style == Style::Ansi && cell_color != color {
+                    write!(f, "\x1b[{}m", Color::Default.ansi_code())?;
+                    if cell_color != Color::Default {
+                        write!(f, "\x1b[{}m", cell_color.ansi_code())?;
+                    }
+                    color = cell_color;
+                }
+                write!(f, "{}", chr)?;
+            }
+            if color != Color::Default {
+                write!(f, "\x1b[{}m", Color::Default.ansi_code())?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.is_clipped() {
+            let (width, height) = self.size();
+            writeln!(
+                f,
+                "[clipped {}x{} to {}x{}]",
+                width,
+                height,
+                width.min(self.max_width),
+                height.min(self.max_height)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+//
+// Something that can be drawn as a picture of the puzzle, like the drawings
+// in the puzzle descriptions.
+//
+pub trait Render {
+    fn render(&self, canvas: &mut Canvas);
+
+    // Whole picture as plain text, handy in tests
+    fn to_plain(&self) -> String {
+        let mut canvas = Canvas::new(Style::Plain);
+        self.render(&mut canvas);
+        canvas.to_string()
+    }
+}
+
+// One line per row, cells drawn with their `Display`
+impl<T: fmt::Display> Render for Grid<T> {
+    fn render(&self, canvas: &mut Canvas) {
+        for row in self.rows() {
+            for cell in row {
+                canvas.text(&cell.to_string());
+            }
+            canvas.newline();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain() {
+        let grid = Grid::from_fn(3, 2, |x, y| x + y);
+
+        assert_eq!(grid.to_plain(), "012\n123\n");
+        assert_eq!(grid.to_plain(), grid.to_string());
+    }
+
+    #[test]
+    fn test_ansi() {
+        let mut canvas = Canvas::new(Style::Ansi);
+        canvas.text(".");
+        canvas.colored("XX", Color::Red);
+        canvas.put('1', Color::cycle(7));
+        canvas.newline();
+
+        assert_eq!(
+            canvas.to_string(),
+            ".\x1b[0m\x1b[31mXX\x1b[0m\x1b[32m1\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut canvas = Canvas::new(Style::Plain).with_limits(3, 2);
+        assert!(canvas.is_empty());
+
+        canvas.draw(&Grid::new(5, 4, '#'));
+        assert!(canvas.is_clipped());
+        assert_eq!(canvas.to_string(), "###\n###\n[clipped 5x4 to 3x2]\n");
+
+        let mut canvas = Canvas::new(Style::Plain).with_limits(3, 2);
+        canvas.line("abc");
+        canvas.text("d");
+        assert!(!canvas.is_clipped());
+        assert_eq!(canvas.to_string(), "abc\nd\n");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::error::Error;
+use crate::image::Image;
+use crate::input::{InputError, Source};
+use crate::random::Rng;
+use crate::render::Canvas;
+use std::fmt;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+//
+// Answer of a single part. `Display` gives the value submitted to the site,
+// diagnostics are intermediate values worth reporting next to it (which guard,
+// which unit was removed and so on).
+//
+pub trait Answer: Display {
+    fn diagnostics(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+impl Answer for i32 {}
+impl Answer for i64 {}
+impl Answer for u32 {}
+impl Answer for usize {}
+impl Answer for String {}
+
+// Type erased `Answer`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    pub answer: String,
+    pub diagnostics: Vec<(&'static str, String)>,
+}
+
+impl Output {
+    pub fn new<A: Answer>(answer: &A) -> Output {
+        Output {
+            answer: answer.to_string(),
+            diagnostics: answer.diagnostics(),
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.answer)
+    }
+}
+
+//
+// Every day of the puzzle implements `Solution`: the raw input is parsed once
+// and both parts are answered from the parsed value, so callers can run a day
+// programmatically instead of scraping what `main()` prints.
+//
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    // Every line that isn't part of the puzzle's input format is an error, as
+    // are lines that make no puzzle together and would only make a part panic.
+    // Parts return `Error::NoAnswer` for puzzles that parse but can't be solved.
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+    fn part_one(&self, input: &Self::Input) -> Result<Self::Answer1, Error>;
+    fn part_two(&self, input: &Self::Input) -> Result<Self::Answer2, Error>;
+
+    // Picture of the puzzle state for `aoc run --visualize`, days without one
+    // leave the canvas empty
+    fn visualize(&self, _input: &Self::Input, _canvas: &mut Canvas) {}
+
+    // Named pictures of grid shaped state for `aoc run --images <dir>`
+    fn images(&self, _input: &Self::Input) -> Vec<(&'static str, Image)> {
+        vec![]
+    }
+
+    // Random input in the day's format for `aoc gen` and property tests. `size`
+    // counts the day's items (lines, units, ...), None is about the size of a
+    // real puzzle input. Days without a generator return None.
+    fn generate(&self, _rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+
+    // `dirs` are searched for the default input, see `input::inputs_dirs`
+    fn load(&self, source: &Source, dirs: &[PathBuf]) -> Result<Self::Input, Error> {
+        self.parse(&source.read_from(dirs)?)
+            .map_err(|err| invalid_source(source, err))
+    }
+}
+
+//
+// Object safe view of a `Solution`, used by the registry to keep days with
+// different input and answer types side by side.
+//
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, Error>;
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String>;
+    fn load<'a>(
+        &'a self,
+        source: &Source,
+        dirs: &[PathBuf],
+    ) -> Result<Box<dyn Prepared + 'a>, Error>;
+}
+
+// Parse errors of an input read from `source` name it
+fn invalid_source(source: &Source, err: Error) -> Error {
+    match err {
+        Error::Parse(errors) => Error::Input(InputError::Invalid(source.clone(), errors)),
+        err => err,
+    }
+}
+
+// Parsed input of a single day, ready to answer both parts
+pub trait Prepared {
+    fn part_one(&self) -> Result<Output, Error>;
+    fn part_two(&self) -> Result<Output, Error>;
+    fn visualize(&self, canvas: &mut Canvas);
+    fn images(&self) -> Vec<(&'static str, Image)>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<'a, S: Solution> Prepared for Parsed<'a, S> {
+    fn part_one(&self) -> Result<Output, Error> {
+        Ok(Output::new(&self.solution.part_one(&self.input)?))
+    }
+
+    fn part_two(&self) -> Result<Output, Error> {
+        Ok(Output::new(&self.solution.part_two(&self.input)?))
+    }
+
+    fn visualize(&self, canvas: &mut Canvas) {
+        self.solution.visualize(&self.input, canvas)
+    }
+
+    fn images(&self) -> Vec<(&'static str, Image)> {
+        self.solution.images(&self.input)
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Prepared + 'a>, Error> {
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: self.parse(input)?,
+        }))
+    }
+
+    fn generate(&self, rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Solution::generate(self, rng, size)
+    }
+
+    fn load<'a>(
+        &'a self,
+        source: &Source,
+        dirs: &[PathBuf],
+    ) -> Result<Box<dyn Prepared + 'a>, Error> {
+        self.prepare(&source.read_from(dirs)?)
+            .map_err(|err| invalid_source(source, err))
+    }
+}
@@ -0,0 +1,216 @@
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+//
+// Leveled trace events for following what a day does, instead of commented
+// out `println!`s. Events are off by default, `AOC_TRACE` or the runner's `-v`
+// flags turn them on and they are written to stderr as
+//
+//   [debug day7] step completed step=C second=63
+//
+// Use the `info!`, `debug!` and `trace!` macros, fields after the message are
+// only formatted when the level is enabled.
+//
+
+// Name of the environment variable holding the level
+pub const TRACE_VAR: &str = "AOC_TRACE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    // a few events per day
+    Info = 1,
+    // steps of an algorithm
+    Debug = 2,
+    // every iteration of inner loops
+    Trace = 3,
+}
+
+impl Level {
+    // Level of `-v`, `-vv` and `-vvv`
+    pub fn from_verbosity(count: usize) -> Level {
+        match count {
+            0 => Level::Off,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Level> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "0" | "off" => Some(Level::Off),
+            "1" | "info" => Some(Level::Info),
+            "2" | "debug" => Some(Level::Debug),
+            "3" | "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn from_u8(value: u8) -> Level {
+        Level::from_verbosity(value as usize)
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+thread_local! {
+    // events of the current thread while `capture` runs
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+// Level from `AOC_TRACE`, unset or invalid values leave tracing off
+pub fn init_from_env() {
+    let level = env::var(TRACE_VAR)
+        .ok()
+        .and_then(|value| Level::parse(&value))
+        .unwrap_or(Level::Off);
+
+    set_level(level);
+}
+
+// Event as written to stderr, values containing spaces are quoted
+pub fn format_event(
+    level: Level,
+    target: &str,
+    message: &str,
+    fields: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let mut line = format!("[{} {}] {}", level, target, message);
+
+    for (key, value) in fields {
+        let value = value.to_string();
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            line.push_str(&format!(" {}={:?}", key, value));
+        } else {
+            line.push_str(&format!(" {}={}", key, value));
+        }
+    }
+
+    line
+}
+
+// Called by the macros once `enabled` said yes
+pub fn emit(level: Level, target: &str, message: &str, fields: &[(&str, &dyn fmt::Display)]) {
+    let line = format_event(level, target, message, fields);
+
+    CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(events) => events.push(line),
+        None => eprintln!("{}", line),
+    });
+}
+
+//
+// Runs `f` at `level` and returns the events it emitted instead of writing
+// them to stderr. Meant for tests; the level is global, so other threads
+// tracing at the same time write to stderr at that level as well.
+//
+pub fn capture<T, F: FnOnce() -> T>(level: Level, f: F) -> (T, Vec<String>) {
+    let previous = self::level();
+    set_level(level);
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(vec![]));
+
+    let result = f();
+
+    let events = CAPTURED.with(|captured| captured.borrow_mut().take().unwrap_or_default());
+    set_level(previous);
+
+    (result, events)
+}
+
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $target:expr, $message:expr $(; $($key:ident = $value:expr),+ $(,)?)?) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit(
+                $level,
+                $target,
+                $message,
+                &[$($((stringify!($key), &$value as &dyn ::std::fmt::Display)),+)?],
+            );
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($args:tt)*) => { $crate::event!($crate::trace::Level::Info, $($args)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($args:tt)*) => { $crate::event!($crate::trace::Level::Debug, $($args)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($args:tt)*) => { $crate::event!($crate::trace::Level::Trace, $($args)*) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(7), Level::Trace);
+        assert_eq!(Level::parse("DEBUG"), Some(Level::Debug));
+        assert_eq!(Level::parse("3"), Some(Level::Trace));
+        assert_eq!(Level::parse(""), Some(Level::Off));
+        assert_eq!(Level::parse("loud"), None);
+    }
+
+    #[test]
+    fn test_format_event() {
+        assert_eq!(
+            format_event(
+                Level::Debug,
+                "day7",
+                "step completed",
+                &[("step", &'C'), ("done", &"C A")]
+            ),
+            "[debug day7] step completed step=C done=\"C A\""
+        );
+    }
+
+    #[test]
+    fn test_capture() {
+        let ((), events) = capture(Level::Debug, || {
+            crate::info!("test", "started");
+            crate::debug!("test", "step"; n = 1, name = "x");
+            crate::trace!("test", "hidden");
+        });
+
+        assert_eq!(
+            events,
+            vec!["[info test] started", "[debug test] step n=1 name=x"]
+        );
+    }
+}